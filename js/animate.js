@@ -0,0 +1,110 @@
+// Animates items into place while dragging, as set through
+// `Options::animation`.
+//
+// Uses the FLIP technique: the positions of the items of the containers
+// involved are recorded before the shadow moves, and once it has moved, each
+// item that changed place is animated from its old position to its new one.
+// On drop, the item slides from where the mirror image was left. Nothing is
+// animated while the user prefers reduced motion.
+
+export function wasm_animate(drake, duration, easing) {
+  var rects = null;
+  var mirror = null;
+  var parents = [];
+  var animations = new Map();
+
+  function reduced() {
+    return !!window.matchMedia &&
+      window.matchMedia('(prefers-reduced-motion: reduce)').matches;
+  }
+
+  // Records where every item of the containers involved currently is
+  function snapshot() {
+    rects = new Map();
+    drake.containers.concat(parents).forEach(function (container) {
+      for (var i = 0; i < container.children.length; i++) {
+        var item = container.children[i];
+        if (!rects.has(item)) {
+          rects.set(item, item.getBoundingClientRect());
+        }
+      }
+    });
+  }
+
+  // Input that may move the shadow, so positions are recorded right before
+  function track() {
+    if (drake.dragging) {
+      snapshot();
+    }
+  }
+
+  // Slides `el` from the position `first` to where it is now
+  function play(el, first) {
+    if (typeof el.animate !== 'function') {
+      return;
+    }
+    var running = animations.get(el);
+    if (running) {
+      running.cancel();
+    }
+
+    var last = el.getBoundingClientRect();
+    var dx = first.left - last.left;
+    var dy = first.top - last.top;
+    if (!dx && !dy) {
+      return;
+    }
+
+    var animation = el.animate([
+      { transform: 'translate(' + dx + 'px, ' + dy + 'px)' },
+      { transform: 'none' }
+    ], { duration: duration, easing: easing });
+    animations.set(el, animation);
+    animation.onfinish = animation.oncancel = function () {
+      if (animations.get(el) === animation) {
+        animations.delete(el);
+      }
+    };
+  }
+
+  function listen(op) {
+    ['mousemove', 'touchmove', 'keydown'].forEach(function (type) {
+      document[op](type, track, true);
+    });
+  }
+
+  drake.on('cloned', function (clone, original, type) {
+    if (type === 'mirror') {
+      mirror = clone;
+    }
+  });
+  drake.on('drag', function (el, source) {
+    parents = [source];
+    snapshot();
+    listen('addEventListener');
+  });
+  drake.on('shadow', function (el, container) {
+    if (parents.indexOf(container) === -1) {
+      parents.push(container);
+    }
+    if (rects === null || reduced()) {
+      return;
+    }
+    rects.forEach(function (first, item) {
+      if (item.parentNode) {
+        play(item, first);
+      }
+    });
+    rects = null;
+  });
+  drake.on('drop', function (el) {
+    if (mirror !== null && mirror.parentNode && !reduced()) {
+      play(el, mirror.getBoundingClientRect());
+    }
+  });
+  drake.on('dragend', function () {
+    listen('removeEventListener');
+    rects = mirror = null;
+    parents = [];
+  });
+}
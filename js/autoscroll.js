@@ -0,0 +1,90 @@
+// Scrolls containers and the viewport while an item is dragged near their
+// edges, as set through `Options::autoscroll`.
+//
+// Scrolling is driven by `requestAnimationFrame` from the `drag` event until
+// `dragend`, following the last pointer position seen by `mousemove` and
+// `touchmove`. Every scrollable ancestor of the container the shadow is in is
+// scrolled, as well as the viewport.
+
+export function wasm_autoscroll(drake, margin, speed) {
+  var x = null;
+  var y = null;
+  var target = null;
+  var frame = null;
+
+  function track(e) {
+    var point = e.touches ? e.touches[0] : e;
+    if (point) {
+      x = point.clientX;
+      y = point.clientY;
+    }
+  }
+
+  // How far to scroll along an axis, given the pointer position `pos` and
+  // the `start` and `end` edges of the scrolled area. Faster closer to the
+  // edge, and nothing once the pointer is outside of the area.
+  function delta(pos, start, end) {
+    if (pos < start || pos > end) {
+      return 0;
+    }
+    if (pos < start + margin) {
+      return -Math.ceil(speed * (start + margin - pos) / margin);
+    }
+    if (pos > end - margin) {
+      return Math.ceil(speed * (pos - end + margin) / margin);
+    }
+    return 0;
+  }
+
+  function scrolls(style, axis) {
+    var overflow = style[axis];
+    return overflow === 'auto' || overflow === 'scroll';
+  }
+
+  function step() {
+    frame = window.requestAnimationFrame(step);
+    if (x === null || !drake.dragging) {
+      return;
+    }
+
+    var root = document.documentElement;
+    for (var el = target; el && el !== document.body && el !== root;
+      el = el.parentElement) {
+      var rect = el.getBoundingClientRect();
+      var style = window.getComputedStyle(el);
+      if (scrolls(style, 'overflowY') && el.scrollHeight > el.clientHeight) {
+        el.scrollTop += delta(y, rect.top, rect.bottom);
+      }
+      if (scrolls(style, 'overflowX') && el.scrollWidth > el.clientWidth) {
+        el.scrollLeft += delta(x, rect.left, rect.right);
+      }
+    }
+
+    window.scrollBy(
+      delta(x, 0, window.innerWidth),
+      delta(y, 0, window.innerHeight)
+    );
+  }
+
+  function stop() {
+    if (frame !== null) {
+      window.cancelAnimationFrame(frame);
+      frame = null;
+    }
+    document.removeEventListener('mousemove', track, true);
+    document.removeEventListener('touchmove', track, true);
+    x = y = target = null;
+  }
+
+  drake.on('drag', function (el, source) {
+    stop();
+    target = source;
+    document.addEventListener('mousemove', track, true);
+    document.addEventListener('touchmove', track, true);
+    frame = window.requestAnimationFrame(step);
+  });
+  drake.on('shadow', function (el, container) {
+    target = container;
+  });
+  drake.on('dragend', stop);
+}
@@ -0,0 +1,239 @@
+//! Screen reader support
+//!
+//! Requires that feature `web-sys` be turned on.
+//!
+//! An [`Announcer`](Announcer) describes drags to assistive technology. It
+//! adds a visually hidden `aria-live` region to the page, and fills it with
+//! a message whenever an item is picked up, moved, dropped, or put back,
+//! driven by the `drag`, `shadow`, `drop`, and `cancel` events of the
+//! [`Drake`](crate::Drake). This works for drags made with the keyboard
+//! through [`KeyboardController`](crate::keyboard::KeyboardController) as
+//! well as with the mouse.
+//!
+//! While a drag is going on, the item has `aria-grabbed="true"` and each
+//! container has `aria-dropeffect` set to `move` or `copy`. They are set back
+//! to `false` and `none` once the drag ends.
+//!
+//! The wording of every message can be changed through
+//! [`Messages`](Messages), for example to translate it.
+//!
+//! ```no_run
+//! use dragula::*;
+//! use dragula::accessibility::{Announcer, Messages};
+//!
+//! let doc = web_sys::window().unwrap().document().unwrap();
+//! let element = doc.get_element_by_id("drag-container").unwrap();
+//!
+//! let mut drake = dragula(&[element]);
+//! let messages = Messages {
+//!     dropped: String::from("{item} an Position {position} von {count}"),
+//!     ..Messages::default()
+//! };
+//! let announcer = Announcer::new(&mut drake, messages);
+//!
+//! //--snip--
+//! ```
+use crate::drake::DragState;
+use crate::event::*;
+use crate::{Drake, ListenerHandle};
+use web_sys::Element;
+
+const ARIA_GRABBED: &str = "aria-grabbed";
+const ARIA_DROPEFFECT: &str = "aria-dropeffect";
+const ARIA_LABEL: &str = "aria-label";
+
+/// Hides the live region visually, while keeping it in the accessibility
+/// tree
+const VISUALLY_HIDDEN: &str = "position: absolute; width: 1px; \
+    height: 1px; margin: -1px; padding: 0; overflow: hidden; \
+    clip: rect(0, 0, 0, 0); white-space: nowrap; border: 0;";
+
+/// Templates for the messages announced by an [`Announcer`](Announcer)
+///
+/// Each template may contain the following placeholders, which are replaced
+/// when the message is announced:
+///
+/// - `{item}`: the `aria-label` of the item, or else its text
+/// - `{position}`: the position of the item in its container, starting at 1
+/// - `{count}`: the number of items in the container
+/// - `{container}`: the `aria-label` of the container, or else its `id`
+pub struct Messages {
+    /// Announced on `drag`, with the position the item was picked up from
+    pub picked_up: String,
+    /// Announced on `shadow`, with the position the item would be dropped at
+    pub moved: String,
+    /// Announced on `drop`, with the position the item was dropped at
+    pub dropped: String,
+    /// Announced on `cancel`, with the position the item went back to
+    pub cancelled: String,
+}
+
+impl Default for Messages {
+    fn default() -> Self {
+        Messages {
+            picked_up: String::from(
+                "Picked up {item}, position {position} of {count} in \
+                 {container}",
+            ),
+            moved: String::from(
+                "{item} moved to position {position} of {count} in \
+                 {container}",
+            ),
+            dropped: String::from(
+                "Dropped {item} at position {position} of {count} in \
+                 {container}",
+            ),
+            cancelled: String::from(
+                "Cancelled, {item} returned to position {position} of \
+                 {count} in {container}",
+            ),
+        }
+    }
+}
+
+fn label(el: &Element) -> String {
+    el.get_attribute(ARIA_LABEL)
+        .or_else(|| el.text_content())
+        .map(|label| label.trim().to_string())
+        .unwrap_or_default()
+}
+
+fn container_label(container: &Element) -> String {
+    container
+        .get_attribute(ARIA_LABEL)
+        .unwrap_or_else(|| container.id())
+}
+
+impl Messages {
+    /// Fills in `template` for `el`, as it stands in `container`
+    fn format(template: &str, el: &Element, container: &Element) -> String {
+        let mut position = 1;
+        let mut sibling = el.previous_element_sibling();
+        while let Some(el) = sibling {
+            position += 1;
+            sibling = el.previous_element_sibling();
+        }
+        let count = container.child_element_count();
+
+        template
+            .replace("{item}", &label(el))
+            .replace("{position}", &position.to_string())
+            .replace("{count}", &count.to_string())
+            .replace("{container}", &container_label(container))
+    }
+}
+
+struct Announcements {
+    drake: Drake,
+    region: Element,
+    messages: Messages,
+    drag: DragState,
+}
+
+impl Announcements {
+    fn announce(&self, template: &str, el: &Element, container: &Element) {
+        let message = Messages::format(template, el, container);
+        self.region.set_text_content(Some(&message));
+    }
+
+    fn set_dropeffect(&self, effect: &str) {
+        for container in self.drake.container_elements() {
+            // Setting an attribute with a valid name cannot fail
+            let _ = container.set_attribute(ARIA_DROPEFFECT, effect);
+        }
+    }
+
+    fn handle(&mut self, event: DragulaEvent<Element>) {
+        // `dragend` is emitted for the copy when copying, so the item that
+        // was grabbed has to be remembered from `drag`
+        let grabbed = self.drag.origin.as_ref().map(|o| o.item.clone());
+        self.drag.track(&event);
+
+        match event {
+            DragulaEvent::Drag(DragEvent { el, source }) => {
+                let _ = el.set_attribute(ARIA_GRABBED, "true");
+                let effect = if self.drag.copying() { "copy" } else { "move" };
+                self.set_dropeffect(effect);
+                self.announce(&self.messages.picked_up, &el, &source);
+            }
+            DragulaEvent::Shadow(ShadowEvent { el, container, .. }) => {
+                self.announce(&self.messages.moved, &el, &container);
+            }
+            DragulaEvent::Drop(DropEvent { el, target, .. }) => {
+                self.announce(&self.messages.dropped, &el, &target);
+            }
+            DragulaEvent::Cancel(CancelEvent { el, container, .. }) => {
+                self.announce(&self.messages.cancelled, &el, &container);
+            }
+            DragulaEvent::DragEnd(_) => {
+                if let Some(item) = grabbed {
+                    let _ = item.set_attribute(ARIA_GRABBED, "false");
+                }
+                self.set_dropeffect("none");
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Announces the drags of a [`Drake`](crate::Drake) through an `aria-live`
+/// region
+///
+/// See the [module documentation](self) for details. The live region is
+/// removed from the page, and announcements stop, once the `Announcer` is
+/// dropped.
+pub struct Announcer {
+    region: Element,
+    _listener: ListenerHandle,
+}
+
+impl Announcer {
+    /// Starts announcing the drags of `drake`, using `messages`. The live
+    /// region is added to the end of the document body.
+    ///
+    /// # Panics
+    /// Panics if there is no document body to add the live region to
+    pub fn new(drake: &mut Drake, messages: Messages) -> Self {
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .expect("No document to announce drags in");
+        let body = document.body().expect("No document body");
+        let region = document
+            .create_element("div")
+            .expect("Could not create live region");
+
+        let _ = region.set_attribute("aria-live", "assertive");
+        let _ = region.set_attribute("aria-atomic", "true");
+        let _ = region.set_attribute("style", VISUALLY_HIDDEN);
+        body.append_child(&region)
+            .expect("Could not add live region");
+
+        let mut announcements = Announcements {
+            drake: drake.clone(),
+            region: region.clone(),
+            messages,
+            drag: DragState::default(),
+        };
+        let listener =
+            drake.on_element_event(move |event| announcements.handle(event));
+
+        Announcer {
+            region,
+            _listener: listener,
+        }
+    }
+
+    /// Returns the `aria-live` region that messages are announced in
+    pub fn region(&self) -> &Element {
+        &self.region
+    }
+}
+
+impl Drop for Announcer {
+    fn drop(&mut self) {
+        self.region.remove();
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,176 @@
+use super::*;
+use crate::keyboard::KeyboardController;
+use crate::options::CopyValue;
+use crate::test_utils::*;
+use crate::{dragula, dragula_options, Drake, Options};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn announced(announcer: &Announcer) -> String {
+    announcer.region().text_content().unwrap_or_default()
+}
+
+fn labelled(drake: &Drake, element: &Element) -> Vec<Element> {
+    let containers = containers(element);
+    for (i, container) in containers.iter().enumerate() {
+        container
+            .set_attribute("aria-label", &format!("List {}", i + 1))
+            .unwrap();
+    }
+    assert_eq!(drake.container_elements().len(), containers.len());
+    containers
+}
+
+#[wasm_bindgen_test]
+fn announces_pickup_moves_and_drop() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 3);
+
+    let test = |element: &Element| {
+        let mut drake = dragula(&containers(element));
+        let containers = labelled(&drake, element);
+        let announcer = Announcer::new(&mut drake, Messages::default());
+        let _keyboard = KeyboardController::new(&mut drake, element);
+
+        assert_eq!(
+            announcer.region().get_attribute("aria-live").as_deref(),
+            Some("assertive")
+        );
+
+        let item = containers[0].first_element_child().unwrap();
+        item.set_attribute("aria-label", "First").unwrap();
+
+        press(&item, " ");
+        assert_eq!(
+            announced(&announcer),
+            "Picked up First, position 1 of 3 in List 1"
+        );
+        assert_eq!(item.get_attribute(ARIA_GRABBED).as_deref(), Some("true"));
+        for container in &containers {
+            assert_eq!(
+                container.get_attribute(ARIA_DROPEFFECT).as_deref(),
+                Some("move")
+            );
+        }
+
+        press(&item, "ArrowDown");
+        assert_eq!(
+            announced(&announcer),
+            "First moved to position 2 of 3 in List 1"
+        );
+
+        press(&item, "Enter");
+        assert_eq!(
+            announced(&announcer),
+            "Dropped First at position 2 of 3 in List 1"
+        );
+        assert_eq!(item.get_attribute(ARIA_GRABBED).as_deref(), Some("false"));
+        for container in &containers {
+            assert_eq!(
+                container.get_attribute(ARIA_DROPEFFECT).as_deref(),
+                Some("none")
+            );
+        }
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn copy_releases_grabbed_item() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 3);
+
+    let test = |element: &Element| {
+        let options = Options {
+            copy: CopyValue::Bool(true),
+            ..Options::default()
+        };
+        let mut drake = dragula_options(&containers(element), options);
+        let containers = labelled(&drake, element);
+        let announcer = Announcer::new(&mut drake, Messages::default());
+        let _keyboard = KeyboardController::new(&mut drake, element);
+
+        let item = containers[0].first_element_child().unwrap();
+        item.set_attribute("aria-label", "First").unwrap();
+
+        press(&item, " ");
+        assert_eq!(item.get_attribute(ARIA_GRABBED).as_deref(), Some("true"));
+        for container in &containers {
+            assert_eq!(
+                container.get_attribute(ARIA_DROPEFFECT).as_deref(),
+                Some("copy")
+            );
+        }
+
+        press(&item, "ArrowRight");
+        let copy = containers[1].first_element_child().unwrap();
+        assert_ne!(copy, item);
+
+        press(&copy, "Enter");
+        assert_eq!(
+            announced(&announcer),
+            "Dropped First at position 1 of 4 in List 2"
+        );
+        assert_eq!(item.get_attribute(ARIA_GRABBED).as_deref(), Some("false"));
+        assert_ne!(copy.get_attribute(ARIA_GRABBED).as_deref(), Some("true"));
+        for container in &containers {
+            assert_eq!(
+                container.get_attribute(ARIA_DROPEFFECT).as_deref(),
+                Some("none")
+            );
+        }
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn announces_cancel_with_custom_messages() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(1, 2);
+
+    let test = |element: &Element| {
+        let mut drake = dragula(&containers(element));
+        let containers = labelled(&drake, element);
+        let messages = Messages {
+            cancelled: String::from("{item}: {position}/{count} {container}"),
+            ..Messages::default()
+        };
+        let announcer = Announcer::new(&mut drake, messages);
+        let _keyboard = KeyboardController::new(&mut drake, element);
+
+        let item = containers[0].first_element_child().unwrap();
+        item.set_attribute("aria-label", "First").unwrap();
+
+        press(&item, " ");
+        press(&item, "ArrowDown");
+        press(&item, "Escape");
+        assert_eq!(announced(&announcer), "First: 1/2 List 1");
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn removes_region_when_dropped() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(1, 1);
+
+    let test = |element: &Element| {
+        let mut drake = dragula(&containers(element));
+        let announcer = Announcer::new(&mut drake, Messages::default());
+        let region = announcer.region().clone();
+        assert!(region.is_connected());
+
+        drop(announcer);
+        assert!(!region.is_connected());
+    };
+
+    run_dom_test(test, &html);
+}
@@ -0,0 +1,20 @@
+//! Animating items into place, as set through
+//! [`Options::animation`](crate::Options::animation)
+use crate::drake::Drake;
+use crate::options::Animation;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "/js/animate.js")]
+extern "C" {
+    fn wasm_animate(drake: &Drake, duration: u32, easing: &str);
+}
+
+/// Animates the items of `drake` as the shadow moves, and once they are
+/// dropped
+pub(crate) fn attach(drake: &Drake, animation: &Animation) {
+    wasm_animate(drake, animation.duration, &animation.easing);
+}
+
+// The tests emit events, which requires feature `web-sys`
+#[cfg(all(test, feature = "web-sys"))]
+mod test;
@@ -0,0 +1,142 @@
+use super::*;
+use crate::test_utils::*;
+use crate::{dragula_options, Options};
+use js_sys::{Function, Reflect};
+use wasm_bindgen_test::*;
+use web_sys::Element;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn animated_options() -> Options {
+    Options {
+        animation: Some(Animation::default()),
+        ..Options::default()
+    }
+}
+
+/// Replaces `el.animate` with a stub counting its calls
+fn stub_animate(el: &Element) {
+    let animate = Function::new_no_args(
+        "this.animations = (this.animations || 0) + 1; \
+         return { cancel: function () {} };",
+    );
+    Reflect::set(el, &JsValue::from("animate"), &animate).unwrap();
+}
+
+/// How many times `el.animate` was called since it was stubbed
+fn animations(el: &Element) -> u32 {
+    Reflect::get(el, &JsValue::from("animations"))
+        .unwrap()
+        .as_f64()
+        .unwrap_or(0.0) as u32
+}
+
+/// Moves the first item of `container` to its end, and reports the move as
+/// the shadow moving there
+fn move_shadow(drake: &mut Drake, container: &Element) {
+    let item = container.first_element_child().unwrap();
+    drake.start(&item);
+    container.append_child(&item).unwrap();
+    drake.emit_3("shadow", &item.into(), container, container);
+}
+
+/// Makes `matchMedia` report that the user prefers reduced motion, until
+/// the returned function is called back
+fn prefer_reduced_motion() -> JsValue {
+    let window = web_sys::window().unwrap();
+    let key = JsValue::from("matchMedia");
+    let original = Reflect::get(&window, &key).unwrap();
+    let reduced = Function::new_no_args("return { matches: true };");
+    Reflect::set(&window, &key, &reduced).unwrap();
+    original
+}
+
+fn restore_motion(original: &JsValue) {
+    let window = web_sys::window().unwrap();
+    Reflect::set(&window, &JsValue::from("matchMedia"), original).unwrap();
+}
+
+#[wasm_bindgen_test]
+fn shadow_move_animates_displaced_items() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(1, 3);
+
+    let test = |element: &Element| {
+        let container = element.first_element_child().unwrap();
+        let mut drake = dragula_options(
+            std::slice::from_ref(&container),
+            animated_options(),
+        );
+
+        let items = containers(&container);
+        items.iter().for_each(stub_animate);
+
+        move_shadow(&mut drake, &container);
+
+        assert!(items.iter().all(|item| animations(item) == 1));
+        drake.end();
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn drop_animates_from_mirror() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(1, 2);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let mut drake = dragula_options(&containers, animated_options());
+
+        let item = containers[0].first_element_child().unwrap();
+        stub_animate(&item);
+
+        // Dragula only creates a mirror once the mouse moves, so one is made
+        // where the pointer left it
+        let doc = web_sys::window().unwrap().document().unwrap();
+        let mirror = doc.create_element("div").unwrap();
+        mirror
+            .set_attribute("style", "position: fixed; top: 300px; left: 300px")
+            .unwrap();
+        element.append_child(&mirror).unwrap();
+
+        drake.start(&item);
+        drake.emit_3("cloned", &mirror, &item, &JsValue::from("mirror"));
+        containers[0].append_child(&item).unwrap();
+        drake.end();
+
+        assert_eq!(animations(&item), 1);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn reduced_motion_disables_animations() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(1, 3);
+
+    let test = |element: &Element| {
+        let container = element.first_element_child().unwrap();
+        let mut drake = dragula_options(
+            std::slice::from_ref(&container),
+            animated_options(),
+        );
+
+        let items = containers(&container);
+        items.iter().for_each(stub_animate);
+
+        let original = prefer_reduced_motion();
+        move_shadow(&mut drake, &container);
+        drake.end();
+        restore_motion(&original);
+
+        assert!(items.iter().all(|item| animations(item) == 0));
+    };
+
+    run_dom_test(test, &html);
+}
@@ -0,0 +1,19 @@
+//! Scrolling while dragging, as set through
+//! [`Options::autoscroll`](crate::Options::autoscroll)
+use crate::drake::Drake;
+use crate::options::AutoScroll;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "/js/autoscroll.js")]
+extern "C" {
+    fn wasm_autoscroll(drake: &Drake, margin: u32, speed: u32);
+}
+
+/// Scrolls containers and the viewport while items of `drake` are dragged
+/// near their edges
+pub(crate) fn attach(drake: &Drake, autoscroll: AutoScroll) {
+    wasm_autoscroll(drake, autoscroll.margin, autoscroll.speed);
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,93 @@
+use super::*;
+use crate::test_utils::*;
+use crate::{dragula_options, Options};
+use wasm_bindgen_test::*;
+use web_sys::{Element, MouseEvent, MouseEventInit};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Moves the pointer to (`x`, `y`) in the viewport
+fn point_at(x: i32, y: i32) {
+    let init = MouseEventInit::new();
+    init.set_client_x(x);
+    init.set_client_y(y);
+    init.set_bubbles(true);
+    let event =
+        MouseEvent::new_with_mouse_event_init_dict("mousemove", &init).unwrap();
+    let doc = web_sys::window().unwrap().document().unwrap();
+    doc.dispatch_event(&event).unwrap();
+}
+
+/// A container 100px high at the top left of the viewport, holding far more
+/// items than fit
+fn scrolling_container() -> Element {
+    let doc = web_sys::window().unwrap().document().unwrap();
+    let container = doc.create_element("div").unwrap();
+    container
+        .set_attribute(
+            "style",
+            "position: fixed; top: 0; left: 0; width: 200px; height: 100px; \
+             overflow-y: auto",
+        )
+        .unwrap();
+    let items = (0..20).fold(String::new(), |acc, i| {
+        format!("{}<div style=\"height: 50px\">Item {}</div>", acc, i)
+    });
+    container.set_inner_html(&items);
+    doc.body().unwrap().append_child(&container).unwrap();
+    container
+}
+
+#[wasm_bindgen_test]
+async fn scrolls_near_edge_until_dragend() {
+    console_error_panic_hook::set_once();
+
+    let container = scrolling_container();
+    let mut drake = dragula_options(
+        std::slice::from_ref(&container),
+        Options {
+            autoscroll: Some(AutoScroll::default()),
+            ..Options::default()
+        },
+    );
+
+    let item = container.first_element_child().unwrap();
+    drake.start(&item);
+    point_at(100, 95);
+    frames(5).await;
+
+    let scrolled = container.scroll_top();
+    assert!(scrolled > 0);
+
+    drake.end();
+    frames(5).await;
+    assert_eq!(container.scroll_top(), scrolled);
+
+    drake.destroy();
+    container.remove();
+}
+
+#[wasm_bindgen_test]
+async fn does_not_scroll_away_from_edges() {
+    console_error_panic_hook::set_once();
+
+    let container = scrolling_container();
+    let mut drake = dragula_options(
+        std::slice::from_ref(&container),
+        Options {
+            autoscroll: Some(AutoScroll::default()),
+            ..Options::default()
+        },
+    );
+
+    let item = container.first_element_child().unwrap();
+    drake.start(&item);
+    point_at(100, 50);
+    frames(5).await;
+
+    assert_eq!(container.scroll_top(), 0);
+
+    drake.end();
+    drake.destroy();
+    container.remove();
+}
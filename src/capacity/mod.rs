@@ -0,0 +1,223 @@
+//! Limits on the number of items a container can hold
+//!
+//! Requires that feature `web-sys` be turned on.
+//!
+//! A [`Capacity`](Capacity) keeps the most items each container may hold,
+//! either set from Rust with [`set_limit`](Capacity::set_limit), or read from
+//! a `data-max-items` attribute on the container. Once applied to the
+//! [`Options`](crate::Options), full containers no longer accept items from
+//! other containers. Items can still be sorted within a full container,
+//! since the item being dragged is not counted.
+//!
+//! After [`attach`](Capacity::attach) is called, the
+//! [`Drake`](crate::Drake) also emits a `capacity_exceeded` event whenever an
+//! item is held over a full container, which can be listened to with
+//! [`on_capacity_exceeded`](crate::Drake::on_capacity_exceeded).
+//!
+//! ```no_run
+//! use dragula::*;
+//! use dragula::capacity::Capacity;
+//!
+//! let doc = web_sys::window().unwrap().document().unwrap();
+//! let todo = doc.get_element_by_id("todo").unwrap();
+//! let doing = doc.get_element_by_id("doing").unwrap();
+//!
+//! let capacity = Capacity::new();
+//! capacity.set_limit(&doing, 3);
+//!
+//! let options = capacity.apply(Options::default());
+//! let mut drake = dragula_options(&[todo, doing], options);
+//! let _attached = capacity.attach(&mut drake);
+//!
+//! let _listener = drake.on_capacity_exceeded(|_el, container, _source| {
+//!     // Show that `container` is full
+//! });
+//!
+//! //--snip--
+//! ```
+use crate::drake::CAPACITY_EXCEEDED_EVENT;
+use crate::options::Options;
+use crate::{Drake, ListenerHandle};
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::JsValue;
+use web_sys::Element;
+
+/// The attribute read for the limit of containers without one set from Rust
+pub const MAX_ITEMS_ATTRIBUTE: &str = "data-max-items";
+
+/// The class Dragula gives the shadow while it is dragged, which is the copy
+/// rather than the item when copying
+const TRANSIT_CLASS: &str = "gu-transit";
+
+struct State {
+    attribute: String,
+    limits: Vec<(Element, usize)>,
+    drake: Option<Drake>,
+    /// The full container that `capacity_exceeded` was last emitted for,
+    /// so that hovering it only emits once
+    exceeded: Option<Element>,
+}
+
+/// Limits on the number of items containers can hold
+///
+/// See the [module documentation](self) for details. Clones share the same
+/// limits, and the closure installed by [`apply`](Capacity::apply) always
+/// uses the current ones, so limits can be changed after the `Drake` is
+/// created.
+#[derive(Clone)]
+pub struct Capacity {
+    state: Rc<RefCell<State>>,
+}
+
+impl Default for Capacity {
+    fn default() -> Self {
+        Capacity {
+            state: Rc::new(RefCell::new(State {
+                attribute: String::from(MAX_ITEMS_ATTRIBUTE),
+                limits: Vec::new(),
+                drake: None,
+                exceeded: None,
+            })),
+        }
+    }
+}
+
+impl Capacity {
+    /// Creates a `Capacity` with no limits set from Rust, reading the
+    /// [`data-max-items`](MAX_ITEMS_ATTRIBUTE) attribute
+    pub fn new() -> Self {
+        Capacity::default()
+    }
+
+    /// Reads limits from `attribute` instead of `data-max-items`
+    pub fn attribute(self, attribute: &str) -> Self {
+        self.state.borrow_mut().attribute = String::from(attribute);
+        self
+    }
+
+    /// Limits `container` to `max_items` items, taking precedence over its
+    /// attribute
+    pub fn set_limit(&self, container: &Element, max_items: usize) {
+        let mut state = self.state.borrow_mut();
+        match state.limits.iter_mut().find(|(el, _)| el == container) {
+            Some((_, limit)) => *limit = max_items,
+            None => state.limits.push((container.clone(), max_items)),
+        }
+    }
+
+    /// Removes the limit set from Rust for `container`. Its attribute, if
+    /// any, applies again.
+    pub fn remove_limit(&self, container: &Element) {
+        self.state
+            .borrow_mut()
+            .limits
+            .retain(|(el, _)| el != container);
+    }
+
+    /// Returns the most items `container` may hold, if it is limited.
+    /// Attributes that do not parse as a number are ignored.
+    pub fn max_items(&self, container: &Element) -> Option<usize> {
+        let state = self.state.borrow();
+        state
+            .limits
+            .iter()
+            .find(|(el, _)| el == container)
+            .map(|(_, limit)| *limit)
+            .or_else(|| {
+                container
+                    .get_attribute(&state.attribute)
+                    .and_then(|limit| limit.trim().parse().ok())
+            })
+    }
+
+    /// Whether `el` can be dropped into `target` without going over its
+    /// limit. Neither `el` nor the shadow of the current drag are counted,
+    /// so items can be sorted within their own container, and the copy
+    /// placed by a copying drag does not take up the room it asks for.
+    pub fn has_room(&self, el: &Element, target: &Element) -> bool {
+        let max_items = match self.max_items(target) {
+            Some(max_items) => max_items,
+            None => return true,
+        };
+
+        let mut count = 0;
+        let mut child = target.first_element_child();
+        while let Some(item) = child {
+            if item != *el && !item.class_list().contains(TRANSIT_CLASS) {
+                count += 1;
+            }
+            child = item.next_element_sibling();
+        }
+
+        count < max_items
+    }
+
+    fn exceeded(&self, el: &JsValue, target: &Element, source: &JsValue) {
+        let drake = {
+            let mut state = self.state.borrow_mut();
+            if state.exceeded.as_ref() == Some(target) {
+                return;
+            }
+            state.exceeded = Some(target.clone());
+            state.drake.clone()
+        };
+
+        // Emitted without holding the state, since listeners may use it
+        if let Some(drake) = drake {
+            drake.emit_3(CAPACITY_EXCEEDED_EVENT, el, target, source);
+        }
+    }
+
+    /// Wraps the [`accepts`](crate::Options::accepts) closure of `options`,
+    /// so that full containers no longer accept items
+    pub fn apply(&self, options: Options) -> Options {
+        let capacity = self.clone();
+        let mut accepts = options.accepts;
+
+        Options {
+            accepts: Box::new(move |el, target, source, sibling| {
+                if !accepts(el.clone(), target.clone(), source.clone(), sibling)
+                {
+                    return false;
+                }
+
+                let item = el.unchecked_ref::<Element>();
+                let container = target.unchecked_ref::<Element>();
+                if capacity.has_room(item, container) {
+                    capacity.state.borrow_mut().exceeded = None;
+                    true
+                } else {
+                    capacity.exceeded(&el, container, &source);
+                    false
+                }
+            }),
+            ..options
+        }
+    }
+
+    /// Emits `capacity_exceeded` events on `drake`, until it is destroyed.
+    ///
+    /// The returned [`ListenerHandle`](crate::ListenerHandle) should be kept
+    /// for as long as `drake`, since it lets each new drag report the
+    /// containers that an earlier drag already found full.
+    pub fn attach(&self, drake: &mut Drake) -> ListenerHandle {
+        self.state.borrow_mut().drake = Some(drake.clone());
+
+        let capacity = self.clone();
+        let dragend = drake.on_dragend(move |_el| {
+            capacity.state.borrow_mut().exceeded = None;
+        });
+
+        let capacity = self.clone();
+        let destroy = drake.on_destroy(move || {
+            capacity.state.borrow_mut().drake = None;
+        });
+
+        dragend.join(destroy)
+    }
+}
+
+#[cfg(test)]
+mod test;
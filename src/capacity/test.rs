@@ -0,0 +1,163 @@
+use super::*;
+use crate::options::CopyValue;
+use crate::test_utils::*;
+use crate::{dragula_options, Options};
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn limits_from_rust_and_attribute() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(3, 2);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        containers[1].set_attribute("data-max-items", "2").unwrap();
+        containers[2]
+            .set_attribute("data-max-items", "many")
+            .unwrap();
+
+        let capacity = Capacity::new();
+        capacity.set_limit(&containers[0], 5);
+
+        assert_eq!(capacity.max_items(&containers[0]), Some(5));
+        assert_eq!(capacity.max_items(&containers[1]), Some(2));
+        assert_eq!(capacity.max_items(&containers[2]), None);
+
+        capacity.set_limit(&containers[1], 3);
+        assert_eq!(capacity.max_items(&containers[1]), Some(3));
+        capacity.remove_limit(&containers[1]);
+        assert_eq!(capacity.max_items(&containers[1]), Some(2));
+
+        let capacity = capacity.attribute("data-wip");
+        assert_eq!(capacity.max_items(&containers[1]), None);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn dragged_item_not_counted_in_own_container() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 2);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let capacity = Capacity::new();
+        capacity.set_limit(&containers[0], 2);
+
+        let own = containers[0].first_element_child().unwrap();
+        let other = containers[1].first_element_child().unwrap();
+
+        assert!(capacity.has_room(&own, &containers[0]));
+        assert!(!capacity.has_room(&other, &containers[0]));
+        assert!(capacity.has_room(&own, &containers[1]));
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn apply_rejects_full_containers_and_emits_once() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 1);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let capacity = Capacity::new();
+        capacity.set_limit(&containers[1], 1);
+
+        let mut drake = dragula_options(
+            &containers,
+            capacity.apply(Options {
+                accepts: Box::new(|_, _, _, _| true),
+                ..Options::default()
+            }),
+        );
+        let _attached = capacity.attach(&mut drake);
+
+        let exceeded = Rc::new(RefCell::new(Vec::new()));
+        let exceeded_ref = exceeded.clone();
+        let _listener =
+            drake.on_capacity_exceeded(move |_el, container, _source| {
+                exceeded_ref.borrow_mut().push(container);
+            });
+
+        let item = JsValue::from(containers[0].first_element_child().unwrap());
+        let full = JsValue::from(&containers[1]);
+        let source = JsValue::from(&containers[0]);
+
+        assert!(!drake.accepts_impl(&item, &full, &source, &JsValue::NULL));
+        assert!(!drake.accepts_impl(&item, &full, &source, &JsValue::NULL));
+        assert_eq!(*exceeded.borrow(), vec![full.clone()]);
+
+        assert!(drake.accepts_impl(&item, &source, &source, &JsValue::NULL));
+        assert!(!drake.accepts_impl(&item, &full, &source, &JsValue::NULL));
+        assert_eq!(exceeded.borrow().len(), 2);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn copy_shadow_not_counted() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 1);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let capacity = Capacity::new();
+        capacity.set_limit(&containers[1], 2);
+
+        let mut drake = dragula_options(
+            &containers,
+            capacity.apply(Options {
+                copy: CopyValue::Bool(true),
+                ..Options::default()
+            }),
+        );
+        let _attached = capacity.attach(&mut drake);
+
+        let exceeded = Rc::new(RefCell::new(0));
+        let exceeded_ref = exceeded.clone();
+        let _listener =
+            drake.on_capacity_exceeded(move |_el, _container, _source| {
+                *exceeded_ref.borrow_mut() += 1;
+            });
+
+        let copies = Rc::new(RefCell::new(Vec::new()));
+        let copies_ref = copies.clone();
+        let _cloned = drake.on_cloned(move |copy, _original, _kind| {
+            copies_ref.borrow_mut().push(copy);
+        });
+
+        let item = containers[0].first_element_child().unwrap();
+        drake.start(&item);
+        let copy: Element = copies.borrow()[0].clone().unchecked_into();
+
+        // Dragula places the copy as the shadow, marked as in transit, while
+        // still asking whether the original item is accepted
+        copy.class_list().add_1("gu-transit").unwrap();
+        containers[1].append_child(&copy).unwrap();
+
+        let item = JsValue::from(&item);
+        let target = JsValue::from(&containers[1]);
+        let source = JsValue::from(&containers[0]);
+        assert!(drake.accepts_impl(&item, &target, &source, &JsValue::NULL));
+        assert!(drake.accepts_impl(&item, &target, &source, &JsValue::NULL));
+        assert_eq!(*exceeded.borrow(), 0);
+
+        drake.end();
+        assert_eq!(containers[1].child_element_count(), 2);
+        assert!(!capacity.has_room(&item.unchecked_into(), &containers[1]));
+    };
+
+    run_dom_test(test, &html);
+}
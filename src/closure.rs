@@ -1,65 +1,104 @@
+use std::any::Any;
+use std::mem;
 use wasm_bindgen::prelude::*;
 
-pub fn to_js_1<F: 'static>(listener: F) -> JsValue
+/// A JS function backed by a Rust closure. The closure is freed when this is
+/// dropped, after which calling the function from JS will throw.
+pub struct OwnedClosure {
+    function: JsValue,
+    closure: Box<dyn Any>,
+}
+
+impl OwnedClosure {
+    fn new<T>(closure: Closure<T>) -> Self
+    where
+        T: ?Sized + 'static,
+    {
+        OwnedClosure {
+            function: closure.as_ref().clone(),
+            closure: Box::new(closure),
+        }
+    }
+
+    pub fn function(&self) -> &JsValue {
+        &self.function
+    }
+
+    /// Leaks the closure so that the returned function stays valid forever
+    pub fn forget(self) -> JsValue {
+        mem::forget(self.closure);
+        self.function
+    }
+}
+
+pub fn to_js_0<F>(listener: F) -> OwnedClosure
+where
+    F: FnMut() + 'static,
+{
+    let closure = Closure::wrap(Box::new(listener) as Box<dyn FnMut()>);
+    OwnedClosure::new(closure)
+}
+
+pub fn to_js_1<F>(listener: F) -> OwnedClosure
 where
-    F: FnMut(JsValue),
+    F: FnMut(JsValue) + 'static,
 {
     let closure = Closure::wrap(Box::new(listener) as Box<dyn FnMut(JsValue)>);
-    closure.into_js_value()
+    OwnedClosure::new(closure)
 }
 
-pub fn to_js_2<F: 'static>(listener: F) -> JsValue
+pub fn to_js_2<F>(listener: F) -> OwnedClosure
 where
-    F: FnMut(JsValue, JsValue),
+    F: FnMut(JsValue, JsValue) + 'static,
 {
     let closure =
         Closure::wrap(Box::new(listener) as Box<dyn FnMut(JsValue, JsValue)>);
-    closure.into_js_value()
+    OwnedClosure::new(closure)
 }
 
-pub fn to_js_3<F: 'static>(listener: F) -> JsValue
+pub fn to_js_3<F>(listener: F) -> OwnedClosure
 where
-    F: FnMut(JsValue, JsValue, JsValue),
+    F: FnMut(JsValue, JsValue, JsValue) + 'static,
 {
     let closure = Closure::wrap(
         Box::new(listener) as Box<dyn FnMut(JsValue, JsValue, JsValue)>
     );
-    closure.into_js_value()
+    OwnedClosure::new(closure)
 }
 
-pub fn to_js_4<F: 'static>(listener: F) -> JsValue
+pub fn to_js_4<F>(listener: F) -> OwnedClosure
 where
-    F: FnMut(JsValue, JsValue, JsValue, JsValue),
+    F: FnMut(JsValue, JsValue, JsValue, JsValue) + 'static,
 {
     let closure = Closure::wrap(Box::new(listener)
         as Box<dyn FnMut(JsValue, JsValue, JsValue, JsValue)>);
-    closure.into_js_value()
+    OwnedClosure::new(closure)
 }
 
-pub fn to_js_1_ret<F: 'static>(listener: F) -> JsValue
+pub fn to_js_1_ret<F>(listener: F) -> OwnedClosure
 where
-    F: FnMut(JsValue) -> bool,
+    F: FnMut(JsValue) -> bool + 'static,
 {
     let closure =
         Closure::wrap(Box::new(listener) as Box<dyn FnMut(JsValue) -> bool>);
-    closure.into_js_value()
+    OwnedClosure::new(closure)
 }
 
-pub fn to_js_2_ret<F: 'static>(listener: F) -> JsValue
+pub fn to_js_2_ret<F>(listener: F) -> OwnedClosure
 where
-    F: FnMut(JsValue, JsValue) -> bool,
+    F: FnMut(JsValue, JsValue) -> bool + 'static,
 {
     let closure = Closure::wrap(
         Box::new(listener) as Box<dyn FnMut(JsValue, JsValue) -> bool>
     );
-    closure.into_js_value()
+    OwnedClosure::new(closure)
 }
 
-pub fn to_js_4_ret<F: 'static>(listener: F) -> JsValue
+pub fn to_js_4_ret<F>(listener: F) -> OwnedClosure
 where
-    F: FnMut(JsValue, JsValue, JsValue, JsValue) -> bool,
+    F: FnMut(JsValue, JsValue, JsValue, JsValue) -> bool + 'static,
 {
     let closure = Closure::wrap(Box::new(listener)
         as Box<dyn FnMut(JsValue, JsValue, JsValue, JsValue) -> bool>);
-    closure.into_js_value()
+    OwnedClosure::new(closure)
 }
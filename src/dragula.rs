@@ -1,5 +1,8 @@
-use crate::drake::Drake;
+use crate::drake::{Drake, OwnedDrake};
 use crate::options::*;
+#[cfg(feature = "serde")]
+use crate::persistence::Persistence;
+use crate::{animation, autoscroll};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
 
@@ -11,6 +14,13 @@ extern "C" {
     ) -> JsValue;
 }
 
+/// Takes out the options that are handled in Rust rather than by Dragula
+#[cfg(feature = "serde")]
+fn split_persistence(mut options: Options) -> (Options, Option<Persistence>) {
+    let persistence = options.persistence.take();
+    (options, persistence)
+}
+
 /// Activates the dragula drag-and-drop system with sane default options
 ///
 /// The simplest way to activate dragula is to call `dragula` and pass it
@@ -73,8 +83,88 @@ pub fn dragula_options<T>(objs: &[T], options: Options) -> Drake
 where
     T: JsCast + Clone,
 {
+    #[cfg(feature = "serde")]
+    let (options, persistence) = split_persistence(options);
+
+    let autoscroll = options.autoscroll;
+    let animation = options.animation.clone();
+
     let obj_array = objs.iter().map(JsValue::from).collect();
     let options = OptionsImpl::from(options);
-    let drake = wasm_dragula(obj_array, options);
-    drake.into()
+    let drake: Drake = wasm_dragula(obj_array, options).into();
+
+    if let Some(autoscroll) = autoscroll {
+        autoscroll::attach(&drake, autoscroll);
+    }
+    if let Some(animation) = animation {
+        animation::attach(&drake, &animation);
+    }
+
+    #[cfg(feature = "serde")]
+    if let Some(persistence) = persistence {
+        persistence.attach(&drake).forget();
+    }
+
+    drake
+}
+
+/// Activates the dragula drag-and-drop system with provided options, tying
+/// its lifetime to the returned [`OwnedDrake`](crate::OwnedDrake)
+///
+/// Behaves like [`dragula_options`](crate::dragula_options), except that the
+/// closures in `options` are not leaked. Once the `OwnedDrake` is dropped, the
+/// drag-and-drop system is destroyed and those closures are freed.
+///
+/// ### Example:
+/// ```no_run
+/// use dragula::*;
+///
+/// let doc = web_sys::window().unwrap().document().unwrap();
+/// let element = doc.get_element_by_id("drag-container").unwrap();
+///
+/// let options = Options {
+///     revert_on_spill: true,
+///     ..Options::default()
+/// };
+///
+/// let drake = dragula_owned(&[element], options);
+///
+/// // Destroys the drake and frees its closures
+/// drop(drake);
+///
+/// ```
+pub fn dragula_owned<T>(objs: &[T], options: Options) -> OwnedDrake
+where
+    T: JsCast + Clone,
+{
+    #[cfg(feature = "serde")]
+    let (options, persistence) = split_persistence(options);
+
+    let autoscroll = options.autoscroll;
+    let animation = options.animation.clone();
+
+    let obj_array = objs.iter().map(JsValue::from).collect();
+    let (options, closures) = OptionsImpl::with_closures(options);
+    let drake: Drake = wasm_dragula(obj_array, options).into();
+
+    if let Some(autoscroll) = autoscroll {
+        autoscroll::attach(&drake, autoscroll);
+    }
+    if let Some(animation) = animation {
+        animation::attach(&drake, &animation);
+    }
+
+    let drake = OwnedDrake::new(drake, closures);
+
+    #[cfg(feature = "serde")]
+    let drake = {
+        let mut drake = drake;
+        if let Some(persistence) = persistence {
+            let listener = persistence.attach(&drake);
+            drake.keep(listener);
+        }
+        drake
+    };
+
+    drake
 }
@@ -0,0 +1,138 @@
+use super::origin::{insert_at, DragState};
+use super::{Drake, ListenerHandle};
+use crate::event::*;
+use web_sys::Element;
+
+/// A move that the user asked for, but that was not applied to the DOM
+///
+/// Passed to the listener registered with
+/// [`Drake::controlled`](crate::Drake::controlled).
+///
+/// Indices count the element children of a container. `to_index` is the
+/// index `item` ends up at once the move is applied, so for a move within a
+/// single container, removing the entry at `from_index` and inserting it at
+/// `to_index` gives the intended order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MoveIntent {
+    /// The element that was dragged
+    pub item: Element,
+    pub from_container: Element,
+    pub from_index: usize,
+    /// `None` if the item was removed, rather than dropped into a container
+    pub to_container: Option<Element>,
+    /// `None` if the item was removed, rather than dropped into a container
+    pub to_index: Option<usize>,
+    /// Whether a copy of the item should be placed in `to_container`, leaving
+    /// the original where it is. Always `false` when `to_container` is
+    /// `from_container`, since copying within the source container only
+    /// reorders it.
+    pub copy: bool,
+}
+
+impl MoveIntent {
+    /// Whether the item should be removed, rather than moved
+    pub fn is_removal(&self) -> bool {
+        self.to_container.is_none()
+    }
+}
+
+fn revert_drop(state: &DragState, el: &Element) -> Option<MoveIntent> {
+    let origin = state.origin.as_ref()?;
+    let drop = state.indexed_drop(el)?;
+
+    // The copy is taken out even when the drop is reported as a reorder
+    if state.copying() {
+        el.remove();
+        // Sorting within a copy source removes the original
+        if origin.item.parent_element().is_none() {
+            insert_at(&origin.container, &origin.item, origin.index);
+        }
+    } else {
+        insert_at(&origin.container, el, origin.index);
+    }
+
+    Some(MoveIntent {
+        item: origin.item.clone(),
+        from_container: drop.source,
+        from_index: drop.from_index,
+        to_container: Some(drop.target),
+        to_index: Some(drop.to_index),
+        copy: drop.copy,
+    })
+}
+
+fn revert_remove(state: &DragState) -> Option<MoveIntent> {
+    let origin = state.origin.as_ref()?;
+
+    insert_at(&origin.container, &origin.item, origin.index);
+
+    Some(MoveIntent {
+        item: origin.item.clone(),
+        from_container: origin.container.clone(),
+        from_index: origin.index,
+        to_container: None,
+        to_index: None,
+        copy: false,
+    })
+}
+
+/// Controlled mode
+///
+/// Requires that feature `web-sys` be turned on
+impl Drake {
+    /// Puts the `Drake` in controlled mode, for use with frameworks that
+    /// expect to be the only thing changing the DOM, such as virtual DOM
+    /// frameworks.
+    ///
+    /// Whenever an item is dropped or removed, the change Dragula made to the
+    /// DOM is reverted, putting the item back where it was lifted from, and
+    /// a [`MoveIntent`](crate::MoveIntent) describing the change is passed to
+    /// `listener` instead. The framework can then apply the change to its own
+    /// state and render it. Copies are reverted the same way, by removing the
+    /// copy that was dropped.
+    ///
+    /// The `Drake` leaves controlled mode once the returned
+    /// [`ListenerHandle`](crate::ListenerHandle) is dropped.
+    ///
+    /// ### Example:
+    /// ```no_run
+    /// use dragula::*;
+    ///
+    /// let doc = web_sys::window().unwrap().document().unwrap();
+    /// let element = doc.get_element_by_id("drag-container").unwrap();
+    ///
+    /// let mut drake = dragula(&[element]);
+    ///
+    /// let listener = drake.controlled(|intent| {
+    ///     if intent.is_removal() {
+    ///         // Remove the entry at `intent.from_index` from the model
+    ///     } else {
+    ///         // Move the entry at `intent.from_index` to `intent.to_index`
+    ///     }
+    /// });
+    ///
+    /// //--snip--
+    /// ```
+    pub fn controlled<F>(&mut self, mut listener: F) -> ListenerHandle
+    where
+        F: FnMut(MoveIntent) + 'static,
+    {
+        let mut state = DragState::default();
+
+        self.on_element_event(move |event| {
+            state.track(&event);
+
+            let intent = match event {
+                DragulaEvent::Drop(DropEvent { el, .. }) => {
+                    revert_drop(&state, &el)
+                }
+                DragulaEvent::Remove(_) => revert_remove(&state),
+                _ => None,
+            };
+
+            if let Some(intent) = intent {
+                listener(intent);
+            }
+        })
+    }
+}
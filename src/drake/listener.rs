@@ -0,0 +1,74 @@
+use super::Drake;
+use crate::closure::OwnedClosure;
+use std::mem;
+
+/// Keeps an event listener registered on a [`Drake`](crate::Drake)
+///
+/// Returned by each of the `on_*` methods on [`Drake`](crate::Drake). When
+/// the handle is dropped, or [`unsubscribe`](ListenerHandle::unsubscribe) is
+/// called, the listener is removed from the `Drake` and the Rust closure
+/// backing it is freed.
+///
+/// ### Example:
+/// ```no_run
+/// use dragula::*;
+/// # use wasm_bindgen::JsValue;
+///
+/// # let element = JsValue::TRUE;
+/// //--snip--
+///
+/// let mut drake = dragula(&[element]);
+///
+/// let listener = drake.on_drop(|el, target, source, sibling| {
+///     //--snip--
+/// });
+///
+/// // No more `drop` events will be received after this point
+/// listener.unsubscribe();
+///
+/// //--snip--
+/// ```
+#[must_use = "the listener is removed as soon as its handle is dropped"]
+pub struct ListenerHandle {
+    drake: Drake,
+    listeners: Vec<(&'static str, OwnedClosure)>,
+}
+
+impl ListenerHandle {
+    pub(crate) fn new(
+        drake: &Drake,
+        event_name: &'static str,
+        listener: OwnedClosure,
+    ) -> Self {
+        ListenerHandle {
+            drake: drake.clone(),
+            listeners: vec![(event_name, listener)],
+        }
+    }
+
+    /// Combines the listeners of both handles into a single handle
+    pub(crate) fn join(mut self, mut other: ListenerHandle) -> Self {
+        self.listeners.append(&mut other.listeners);
+        self
+    }
+
+    /// Removes the listener from the `Drake` and frees it. This is the same
+    /// as dropping the handle.
+    pub fn unsubscribe(self) {}
+
+    /// Keeps the listener registered for the rest of the lifetime of the
+    /// page. The closure backing it will never be freed.
+    pub fn forget(mut self) {
+        for (_, listener) in mem::take(&mut self.listeners) {
+            listener.forget();
+        }
+    }
+}
+
+impl Drop for ListenerHandle {
+    fn drop(&mut self) {
+        for (event_name, listener) in self.listeners.drain(..) {
+            self.drake.off(event_name, listener.function());
+        }
+    }
+}
@@ -1,16 +1,39 @@
-use crate::closure;
-#[cfg(any(feature = "js-sys", test))]
-use js_sys::Array;
+use crate::closure::{self, OwnedClosure};
+use crate::event::*;
+use crate::options::Direction;
+use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
+#[cfg(feature = "web-sys")]
+use web_sys::Element;
+
+#[cfg(feature = "web-sys")]
+mod controlled;
+mod listener;
+#[cfg(feature = "web-sys")]
+mod order;
+#[cfg(feature = "web-sys")]
+mod origin;
+mod owned;
+#[cfg(feature = "futures")]
+mod stream;
+#[cfg(feature = "web-sys")]
+pub use controlled::MoveIntent;
+pub use listener::ListenerHandle;
+#[cfg(feature = "web-sys")]
+pub use order::{Layout, OrderError};
+#[cfg(feature = "web-sys")]
+pub(crate) use origin::{index_where, insert_before, DragState};
+pub use owned::OwnedDrake;
+#[cfg(feature = "futures")]
+pub use stream::EventStream;
 
 #[wasm_bindgen]
 extern "C" {
     /// Interface provided by Dragula to interact with active drag-and-drop system
     ///
     /// ### Example:
-    #[cfg_attr(feature = "js-sys", doc = "```no_run")]
-    #[cfg_attr(not(feature = "js-sys"), doc = "```no_compile")]
+    /// ```no_run
     /// use dragula::*;
     ///
     /// let doc = web_sys::window().unwrap().document().unwrap();
@@ -23,6 +46,7 @@ extern "C" {
     ///
     /// ```
     #[wasm_bindgen(js_name = Drake)]
+    #[derive(Debug, Clone)]
     pub type Drake;
 
     /// This property will be `true` whenever an element is being dragged.
@@ -42,14 +66,51 @@ extern "C" {
     #[wasm_bindgen(method)]
     pub fn remove(this: &Drake);
 
-    /// Removes all drag and drop events used by `dragula` to manage drag and
-    /// drop between the `containers`. If `destroy` is called while an element
-    /// is being dragged, the drag will be effectively cancelled.
+    #[wasm_bindgen(method, js_name = destroy)]
+    fn destroy_impl(this: &Drake);
+
     #[wasm_bindgen(method)]
-    pub fn destroy(this: &Drake);
+    fn on(this: &Drake, event_type: &str, listener: &JsValue);
 
     #[wasm_bindgen(method)]
-    fn on(this: &Drake, event_type: &str, listener: JsValue);
+    fn off(this: &Drake, event_type: &str, listener: &JsValue);
+
+    #[wasm_bindgen(method)]
+    fn emit(this: &Drake, event_type: &str);
+
+    #[cfg(feature = "web-sys")]
+    #[wasm_bindgen(method, js_name = emit)]
+    pub(crate) fn emit_1(this: &Drake, event_type: &str, arg_1: &JsValue);
+
+    #[cfg(feature = "web-sys")]
+    #[wasm_bindgen(method, js_name = emit)]
+    pub(crate) fn emit_2(
+        this: &Drake,
+        event_type: &str,
+        arg_1: &JsValue,
+        arg_2: &JsValue,
+    );
+
+    #[cfg(feature = "web-sys")]
+    #[wasm_bindgen(method, js_name = emit)]
+    pub(crate) fn emit_3(
+        this: &Drake,
+        event_type: &str,
+        arg_1: &JsValue,
+        arg_2: &JsValue,
+        arg_3: &JsValue,
+    );
+
+    #[cfg(feature = "web-sys")]
+    #[wasm_bindgen(method, js_name = emit)]
+    pub(crate) fn emit_4(
+        this: &Drake,
+        event_type: &str,
+        arg_1: &JsValue,
+        arg_2: &JsValue,
+        arg_3: &JsValue,
+        arg_4: &JsValue,
+    );
 
     /// If an element managed by `Drake` is currently being dragged, this method
     /// will gracefully cancel the drag action.
@@ -77,7 +138,7 @@ extern "C" {
     pub fn cancel_with_revert(this: &Drake, revert: bool);
 
     #[wasm_bindgen(method, getter = containers)]
-    fn containers_getter_impl(this: &Drake) -> JsValue;
+    fn containers_getter_impl(this: &Drake) -> Vec<JsValue>;
 
     #[wasm_bindgen(method, setter = containers)]
     fn containers_setter_impl(this: &Drake, val: Box<[JsValue]>);
@@ -87,17 +148,56 @@ extern "C" {
 
     #[wasm_bindgen(method, js_name = canMove)]
     fn can_move_impl(this: &Drake, item: JsValue) -> bool;
+
+    // Added by `wasm_dragula`, since Dragula keeps its options to itself
+    #[wasm_bindgen(method, js_name = isContainer)]
+    fn is_container_impl(this: &Drake, el: JsValue) -> bool;
+
+    #[cfg(feature = "web-sys")]
+    #[wasm_bindgen(method, js_name = accepts)]
+    pub(crate) fn accepts_impl(
+        this: &Drake,
+        el: &JsValue,
+        target: &JsValue,
+        source: &JsValue,
+        sibling: &JsValue,
+    ) -> bool;
+
+    #[wasm_bindgen(method, getter = direction)]
+    fn direction_impl(this: &Drake) -> String;
 }
 
+/// Emitted by [`Drake::destroy`], since Dragula has no event of its own
+const DESTROY_EVENT: &str = "destroy";
+
+/// Emitted by [`Capacity`](crate::capacity::Capacity) when an item is held
+/// over a full container
+#[cfg(feature = "web-sys")]
+pub(crate) const CAPACITY_EXCEEDED_EVENT: &str = "capacity_exceeded";
+
 impl Drake {
+    /// Removes all drag and drop events used by `dragula` to manage drag and
+    /// drop between the `containers`. If `destroy` is called while an element
+    /// is being dragged, the drag will be effectively cancelled.
+    pub fn destroy(&self) {
+        self.destroy_impl();
+        self.emit(DESTROY_EVENT);
+    }
+
     /// Gets the active containers currently allowing dragging
-    ///
-    /// Requires that feature `js-sys` be turned on (it is on by default)
-    #[cfg(any(feature = "js-sys", test))]
     pub fn containers(&self) -> Vec<JsValue> {
-        let containers = self.containers_getter_impl();
-        let containers = Array::from(&containers);
-        containers.to_vec()
+        self.containers_getter_impl()
+    }
+
+    /// Gets the active containers currently allowing dragging, as elements
+    ///
+    /// Requires that feature `web-sys` be turned on
+    #[cfg(feature = "web-sys")]
+    pub fn container_elements(&self) -> Vec<Element> {
+        self.containers()
+            .into_iter()
+            .map(JsCast::unchecked_into)
+            .collect()
     }
 
     /// Sets the list of active containers for dragging. This overrides the
@@ -106,15 +206,11 @@ impl Drake {
     where
         T: JsCast + Clone,
     {
-        let obj_array =
-            objs.iter().cloned().map(|o| JsValue::from(&o)).collect();
+        let obj_array = objs.iter().map(JsValue::from).collect();
         self.containers_setter_impl(obj_array);
     }
 
     /// Adds to the list of active containers for dragging
-    ///
-    /// Requires that feature `js-sys` be turned on (it is on by default)
-    #[cfg(feature = "js-sys")]
     pub fn add_container<T>(&mut self, obj: T)
     where
         T: JsCast,
@@ -125,6 +221,63 @@ impl Drake {
         self.set_containers(&containers);
     }
 
+    /// Removes from the list of active containers for dragging. Does nothing
+    /// if `obj` is not in the list.
+    ///
+    /// Elements that are containers because of
+    /// [`Options::is_container`](crate::Options::is_container) are not
+    /// affected.
+    pub fn remove_container<T>(&mut self, obj: &T)
+    where
+        T: JsCast,
+    {
+        let container_to_remove = JsValue::from(obj);
+        let mut containers = self.containers();
+        containers.retain(|container| *container != container_to_remove);
+        self.set_containers(&containers);
+    }
+
+    /// Returns whether `obj` is a container, either because it is in the list
+    /// of active containers, or because
+    /// [`Options::is_container`](crate::Options::is_container) returns `true`
+    /// for it.
+    pub fn has_container<T>(&self, obj: &T) -> bool
+    where
+        T: JsCast,
+    {
+        let obj = JsValue::from(obj);
+        self.containers().contains(&obj) || self.is_container_impl(obj)
+    }
+
+    /// Returns the container that `item` is in, which is the closest
+    /// ancestor of `item` that is a container, as decided by
+    /// [`has_container`](Drake::has_container). Returns `None` if `item` is
+    /// not in a container.
+    ///
+    /// Requires that feature `web-sys` be turned on
+    #[cfg(feature = "web-sys")]
+    pub fn container_of(&self, item: &Element) -> Option<Element> {
+        let mut parent = item.parent_element();
+        while let Some(el) = parent {
+            if self.has_container(&el) {
+                return Some(el);
+            }
+            parent = el.parent_element();
+        }
+        None
+    }
+
+    /// Returns the [`direction`](crate::Options::direction) the `Drake` was
+    /// created with
+    pub fn direction(&self) -> Direction {
+        const HORIZONTAL: &str = "horizontal";
+
+        match self.direction_impl().as_str() {
+            HORIZONTAL => Direction::Horizontal,
+            _ => Direction::Vertical,
+        }
+    }
+
     /// Enter drag mode **without a shadow**. This function is most useful when
     /// providing complementary keyboard shortcuts to an existing drag and drop
     /// solution. Even though a shadow won't be created at first, the user will
@@ -154,34 +307,49 @@ impl Drake {
         self.can_move_impl(item)
     }
 
+    fn listen(
+        &self,
+        event_name: &'static str,
+        listener: OwnedClosure,
+    ) -> ListenerHandle {
+        self.on(event_name, listener.function());
+        ListenerHandle::new(self, event_name, listener)
+    }
+
     /// Sets callback for `drag` event.
     /// Callback will be passed arguments `(el, source)`
     /// The `drag` event implies that
     /// `el` was lifted from `source`.
-    pub fn on_drag<F: 'static>(&mut self, listener: F)
+    ///
+    /// The listener is removed once the returned
+    /// [`ListenerHandle`](crate::ListenerHandle) is dropped.
+    pub fn on_drag<F>(&mut self, listener: F) -> ListenerHandle
     where
-        F: FnMut(JsValue, JsValue),
+        F: FnMut(JsValue, JsValue) + 'static,
     {
         const EVENT_NAME: &str = "drag";
 
         let listener = closure::to_js_2(listener);
 
-        self.on(EVENT_NAME, listener);
+        self.listen(EVENT_NAME, listener)
     }
 
     /// Sets callback for `dragend` event.
     /// Callback will be passed argument `(el)`
     /// The `dragend` event implies that
     /// dragging event for `el` ended with either `cancel`, `remove`, or `drop`.
-    pub fn on_dragend<F: 'static>(&mut self, listener: F)
+    ///
+    /// The listener is removed once the returned
+    /// [`ListenerHandle`](crate::ListenerHandle) is dropped.
+    pub fn on_dragend<F>(&mut self, listener: F) -> ListenerHandle
     where
-        F: FnMut(JsValue),
+        F: FnMut(JsValue) + 'static,
     {
         const EVENT_NAME: &str = "dragend";
 
         let listener = closure::to_js_1(listener);
 
-        self.on(EVENT_NAME, listener);
+        self.listen(EVENT_NAME, listener)
     }
 
     /// Sets callback for `drop` event.
@@ -189,15 +357,18 @@ impl Drake {
     /// The `drop` event implies that
     /// `el` was dropped into `target` before a `sibling` element, and
     /// originally came from `source`.
-    pub fn on_drop<F: 'static>(&mut self, listener: F)
+    ///
+    /// The listener is removed once the returned
+    /// [`ListenerHandle`](crate::ListenerHandle) is dropped.
+    pub fn on_drop<F>(&mut self, listener: F) -> ListenerHandle
     where
-        F: FnMut(JsValue, JsValue, JsValue, JsValue),
+        F: FnMut(JsValue, JsValue, JsValue, JsValue) + 'static,
     {
         const EVENT_NAME: &str = "drop";
 
         let listener = closure::to_js_4(listener);
 
-        self.on(EVENT_NAME, listener);
+        self.listen(EVENT_NAME, listener)
     }
 
     /// Sets callback for `cancel` event.
@@ -205,15 +376,18 @@ impl Drake {
     /// The `cancel` event implies that
     /// `el` was being dragged but it got nowhere and went back into
     /// `container`, its last stable parent; `el` originally came from `source`.
-    pub fn on_cancel<F: 'static>(&mut self, listener: F)
+    ///
+    /// The listener is removed once the returned
+    /// [`ListenerHandle`](crate::ListenerHandle) is dropped.
+    pub fn on_cancel<F>(&mut self, listener: F) -> ListenerHandle
     where
-        F: FnMut(JsValue, JsValue, JsValue),
+        F: FnMut(JsValue, JsValue, JsValue) + 'static,
     {
         const EVENT_NAME: &str = "cancel";
 
         let listener = closure::to_js_3(listener);
 
-        self.on(EVENT_NAME, listener);
+        self.listen(EVENT_NAME, listener)
     }
 
     /// Sets callback for `remove` event.
@@ -222,15 +396,18 @@ impl Drake {
     /// `el` was being dragged but it got nowhere and it was removed from the
     /// DOM. Its last stable parent was `container`, and originally came from
     /// `source`.
-    pub fn on_remove<F: 'static>(&mut self, listener: F)
+    ///
+    /// The listener is removed once the returned
+    /// [`ListenerHandle`](crate::ListenerHandle) is dropped.
+    pub fn on_remove<F>(&mut self, listener: F) -> ListenerHandle
     where
-        F: FnMut(JsValue, JsValue, JsValue),
+        F: FnMut(JsValue, JsValue, JsValue) + 'static,
     {
         const EVENT_NAME: &str = "remove";
 
         let listener = closure::to_js_3(listener);
 
-        self.on(EVENT_NAME, listener);
+        self.listen(EVENT_NAME, listener)
     }
 
     /// Sets callback for `shadow` event.
@@ -239,30 +416,36 @@ impl Drake {
     /// `el`, _the visual aid shadow_, was moved into `container`. May trigger
     /// many times as the position of `el` changes, even within the same
     /// `container`; `el` originally came from `source`.
-    pub fn on_shadow<F: 'static>(&mut self, listener: F)
+    ///
+    /// The listener is removed once the returned
+    /// [`ListenerHandle`](crate::ListenerHandle) is dropped.
+    pub fn on_shadow<F>(&mut self, listener: F) -> ListenerHandle
     where
-        F: FnMut(JsValue, JsValue, JsValue),
+        F: FnMut(JsValue, JsValue, JsValue) + 'static,
     {
         const EVENT_NAME: &str = "shadow";
 
         let listener = closure::to_js_3(listener);
 
-        self.on(EVENT_NAME, listener);
+        self.listen(EVENT_NAME, listener)
     }
 
     /// Sets callback for `over` event.
     /// Callback will be passed argument `(el, container, source)`
     /// The `over` event implies that
     /// `el` is over `container`, and originally came from `source`.
-    pub fn on_over<F: 'static>(&mut self, listener: F)
+    ///
+    /// The listener is removed once the returned
+    /// [`ListenerHandle`](crate::ListenerHandle) is dropped.
+    pub fn on_over<F>(&mut self, listener: F) -> ListenerHandle
     where
-        F: FnMut(JsValue, JsValue, JsValue),
+        F: FnMut(JsValue, JsValue, JsValue) + 'static,
     {
         const EVENT_NAME: &str = "over";
 
         let listener = closure::to_js_3(listener);
 
-        self.on(EVENT_NAME, listener);
+        self.listen(EVENT_NAME, listener)
     }
 
     /// Sets callback for `out` event.
@@ -270,15 +453,18 @@ impl Drake {
     /// The `out` event implies that
     /// `el` was dragged out of `container` or dropped, and originally came from
     /// `source`.
-    pub fn on_out<F: 'static>(&mut self, listener: F)
+    ///
+    /// The listener is removed once the returned
+    /// [`ListenerHandle`](crate::ListenerHandle) is dropped.
+    pub fn on_out<F>(&mut self, listener: F) -> ListenerHandle
     where
-        F: FnMut(JsValue, JsValue, JsValue),
+        F: FnMut(JsValue, JsValue, JsValue) + 'static,
     {
         const EVENT_NAME: &str = "out";
 
         let listener = closure::to_js_3(listener);
 
-        self.on(EVENT_NAME, listener);
+        self.listen(EVENT_NAME, listener)
     }
 
     /// Sets callback for `cloned` event.
@@ -286,15 +472,235 @@ impl Drake {
     /// The `cloned` event implies that
     /// DOM element `original` was cloned as `clone`, of `type` _(`'mirror'` or
     /// `'copy'`)_. Fired for mirror images and when `copy: true`.
-    pub fn on_cloned<F: 'static>(&mut self, listener: F)
+    ///
+    /// The listener is removed once the returned
+    /// [`ListenerHandle`](crate::ListenerHandle) is dropped.
+    pub fn on_cloned<F>(&mut self, listener: F) -> ListenerHandle
     where
-        F: FnMut(JsValue, JsValue, JsValue),
+        F: FnMut(JsValue, JsValue, JsValue) + 'static,
     {
         const EVENT_NAME: &str = "cloned";
 
         let listener = closure::to_js_3(listener);
 
-        self.on(EVENT_NAME, listener);
+        self.listen(EVENT_NAME, listener)
+    }
+
+    /// Sets callback for when [`destroy`](Drake::destroy) is called.
+    /// Callback will be passed no arguments.
+    /// Unlike the other events, this one is emitted by this crate rather than
+    /// by Dragula, so it will not fire if `destroy` is called from Javascript.
+    ///
+    /// The listener is removed once the returned
+    /// [`ListenerHandle`](crate::ListenerHandle) is dropped.
+    pub fn on_destroy<F>(&mut self, listener: F) -> ListenerHandle
+    where
+        F: FnMut() + 'static,
+    {
+        let listener = closure::to_js_0(listener);
+
+        self.listen(DESTROY_EVENT, listener)
+    }
+
+    /// Sets callback for `capacity_exceeded` event.
+    /// Callback will be passed arguments `(el, container, source)`
+    /// The `capacity_exceeded` event implies that `el`, which originally came
+    /// from `source`, was held over `container`, but could not be dropped
+    /// there because `container` is full. Fired once each time a full
+    /// container is hovered.
+    ///
+    /// Like `destroy`, this event is emitted by this crate rather than by
+    /// Dragula, and only once the `Drake` has been attached to a
+    /// [`Capacity`](crate::capacity::Capacity).
+    ///
+    /// The listener is removed once the returned
+    /// [`ListenerHandle`](crate::ListenerHandle) is dropped.
+    ///
+    /// Requires that feature `web-sys` be turned on
+    #[cfg(feature = "web-sys")]
+    pub fn on_capacity_exceeded<F>(&mut self, listener: F) -> ListenerHandle
+    where
+        F: FnMut(JsValue, JsValue, JsValue) + 'static,
+    {
+        let listener = closure::to_js_3(listener);
+
+        self.listen(CAPACITY_EXCEEDED_EVENT, listener)
+    }
+
+    /// Sets a single callback for every event emitted by the `Drake`.
+    /// Callback will be passed a [`DragulaEvent`](crate::DragulaEvent)
+    /// describing the event and its arguments, so that one `match` can
+    /// handle all of them.
+    ///
+    /// Every listener is removed once the returned
+    /// [`ListenerHandle`](crate::ListenerHandle) is dropped.
+    ///
+    /// ### Example:
+    /// ```no_run
+    /// use dragula::*;
+    /// use dragula::event::DropEvent;
+    /// # use wasm_bindgen::JsValue;
+    ///
+    /// # let element = JsValue::TRUE;
+    /// //--snip--
+    ///
+    /// let mut drake = dragula(&[element]);
+    ///
+    /// let listener = drake.on_event(|event| match event {
+    ///     DragulaEvent::Drop(DropEvent { el, sibling: None, .. }) => {
+    ///         // `el` was placed at the end of its new container
+    ///     }
+    ///     DragulaEvent::Cancel(_) => {}
+    ///     _ => {}
+    /// });
+    ///
+    /// //--snip--
+    /// ```
+    pub fn on_event<F>(&mut self, listener: F) -> ListenerHandle
+    where
+        F: FnMut(DragulaEvent) + 'static,
+    {
+        let dispatcher = Rc::new(Dispatcher::new(listener));
+
+        let d = dispatcher.clone();
+        let drag = self.on_drag(move |el, source| {
+            d.dispatch(DragulaEvent::Drag(DragEvent { el, source }));
+        });
+
+        let d = dispatcher.clone();
+        let dragend = self.on_dragend(move |el| {
+            d.dispatch(DragulaEvent::DragEnd(DragEndEvent { el }));
+        });
+
+        let d = dispatcher.clone();
+        let drop = self.on_drop(move |el, target, source, sib| {
+            d.dispatch(DragulaEvent::Drop(DropEvent {
+                el,
+                target,
+                source,
+                sibling: sibling(sib),
+            }));
+        });
+
+        let d = dispatcher.clone();
+        let cancel = self.on_cancel(move |el, container, source| {
+            d.dispatch(DragulaEvent::Cancel(CancelEvent {
+                el,
+                container,
+                source,
+            }));
+        });
+
+        let d = dispatcher.clone();
+        let remove = self.on_remove(move |el, container, source| {
+            d.dispatch(DragulaEvent::Remove(RemoveEvent {
+                el,
+                container,
+                source,
+            }));
+        });
+
+        let d = dispatcher.clone();
+        let shadow = self.on_shadow(move |el, container, source| {
+            d.dispatch(DragulaEvent::Shadow(ShadowEvent {
+                el,
+                container,
+                source,
+            }));
+        });
+
+        let d = dispatcher.clone();
+        let over = self.on_over(move |el, container, source| {
+            d.dispatch(DragulaEvent::Over(OverEvent {
+                el,
+                container,
+                source,
+            }));
+        });
+
+        let d = dispatcher.clone();
+        let out = self.on_out(move |el, container, source| {
+            d.dispatch(DragulaEvent::Out(OutEvent {
+                el,
+                container,
+                source,
+            }));
+        });
+
+        let d = dispatcher;
+        let cloned = self.on_cloned(move |clone, original, kind| {
+            d.dispatch(DragulaEvent::Cloned(ClonedEvent {
+                clone,
+                original,
+                kind: CloneKind::from(kind),
+            }));
+        });
+
+        drag.join(dragend)
+            .join(drop)
+            .join(cancel)
+            .join(remove)
+            .join(shadow)
+            .join(over)
+            .join(out)
+            .join(cloned)
+    }
+
+    /// Sets a single callback for every event emitted by the `Drake`, with
+    /// the elements involved typed as [`Element`](web_sys::Element).
+    /// Otherwise identical to [`on_event`](Drake::on_event).
+    ///
+    /// Requires that feature `web-sys` be turned on
+    #[cfg(feature = "web-sys")]
+    pub fn on_element_event<F>(&mut self, mut listener: F) -> ListenerHandle
+    where
+        F: FnMut(DragulaEvent<Element>) + 'static,
+    {
+        self.on_event(move |event| listener(event.unchecked_into()))
+    }
+
+    /// Sets a callback for when an item is dropped, which receives the index
+    /// it was lifted from and the index it was dropped at, along with the
+    /// containers involved.
+    /// See [`IndexedDropEvent`](crate::event::IndexedDropEvent).
+    ///
+    /// The listener is removed once the returned
+    /// [`ListenerHandle`](crate::ListenerHandle) is dropped.
+    ///
+    /// Requires that feature `web-sys` be turned on
+    ///
+    /// ### Example:
+    /// ```no_run
+    /// use dragula::*;
+    ///
+    /// let doc = web_sys::window().unwrap().document().unwrap();
+    /// let element = doc.get_element_by_id("drag-container").unwrap();
+    ///
+    /// let mut drake = dragula(&[element]);
+    ///
+    /// let listener = drake.on_indexed_drop(|drop| {
+    ///     let moved = format!("{} -> {}", drop.from_index, drop.to_index);
+    ///     //--snip--
+    /// });
+    ///
+    /// //--snip--
+    /// ```
+    #[cfg(feature = "web-sys")]
+    pub fn on_indexed_drop<F>(&mut self, mut listener: F) -> ListenerHandle
+    where
+        F: FnMut(IndexedDropEvent) + 'static,
+    {
+        let mut state = DragState::default();
+
+        self.on_element_event(move |event| {
+            state.track(&event);
+
+            if let DragulaEvent::Drop(DropEvent { el, .. }) = event {
+                if let Some(drop) = state.indexed_drop(&el) {
+                    listener(drop);
+                }
+            }
+        })
     }
 }
 
@@ -0,0 +1,187 @@
+use super::origin::insert_before;
+use super::Drake;
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+use web_sys::Element;
+
+/// The order of the items in the containers of a [`Drake`](crate::Drake)
+///
+/// Returned by [`Drake::serialize_order`](crate::Drake::serialize_order),
+/// and passed to [`Drake::restore_order`](crate::Drake::restore_order).
+/// Containers and items are identified by the value of `attribute`, such as
+/// `data-id`, on each of them.
+///
+/// With feature `serde` turned on, `Layout` implements `Serialize` and
+/// `Deserialize`, so that it can be stored and loaded back.
+///
+/// Requires that feature `web-sys` be turned on
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Layout {
+    /// The attribute holding the id of each container and item
+    pub attribute: String,
+    /// The id of each container, along with the ids of its items in order
+    pub containers: Vec<(String, Vec<String>)>,
+}
+
+/// Why an order could not be serialized or restored
+///
+/// Requires that feature `web-sys` be turned on
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrderError {
+    /// A container, or one of its items, lacks the id attribute
+    MissingId(Element),
+    /// No container of the `Drake` has this id
+    UnknownContainer(String),
+    /// No item in the containers of the `Drake` has this id
+    UnknownItem(String),
+    /// This item id is listed more than once
+    DuplicateItem(String),
+}
+
+impl fmt::Display for OrderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OrderError::MissingId(el) => {
+                write!(f, "element <{}> has no id", el.tag_name())
+            }
+            OrderError::UnknownContainer(id) => {
+                write!(f, "no container with id {:?}", id)
+            }
+            OrderError::UnknownItem(id) => {
+                write!(f, "no item with id {:?}", id)
+            }
+            OrderError::DuplicateItem(id) => {
+                write!(f, "item {:?} is listed more than once", id)
+            }
+        }
+    }
+}
+
+impl Error for OrderError {}
+
+fn children(container: &Element) -> Vec<Element> {
+    let children = container.children();
+    (0..children.length())
+        .filter_map(|i| children.item(i))
+        .collect()
+}
+
+fn id_of(el: &Element, attribute: &str) -> Result<String, OrderError> {
+    el.get_attribute(attribute)
+        .ok_or_else(|| OrderError::MissingId(el.clone()))
+}
+
+/// Ordering
+///
+/// Requires that feature `web-sys` be turned on
+impl Drake {
+    /// Returns the order of the items in each container of the `Drake`,
+    /// identifying containers and items by the value of `attribute`
+    ///
+    /// Only the containers passed to [`dragula`](crate::dragula()) or added
+    /// later are included, not those found through
+    /// [`Options::is_container`](crate::Options::is_container).
+    ///
+    /// ### Example:
+    /// ```no_run
+    /// use dragula::*;
+    ///
+    /// let doc = web_sys::window().unwrap().document().unwrap();
+    /// let element = doc.get_element_by_id("drag-container").unwrap();
+    ///
+    /// let drake = dragula(&[element]);
+    ///
+    /// //--snip--
+    ///
+    /// let layout = drake.serialize_order("data-id").unwrap();
+    ///
+    /// //--snip--
+    ///
+    /// drake.restore_order(&layout).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`OrderError::MissingId`](OrderError::MissingId) if a
+    /// container or item lacks `attribute`
+    pub fn serialize_order(
+        &self,
+        attribute: &str,
+    ) -> Result<Layout, OrderError> {
+        let containers = self
+            .container_elements()
+            .iter()
+            .map(|container| {
+                let items = children(container)
+                    .iter()
+                    .map(|item| id_of(item, attribute))
+                    .collect::<Result<_, _>>()?;
+                Ok((id_of(container, attribute)?, items))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Layout {
+            attribute: String::from(attribute),
+            containers,
+        })
+    }
+
+    /// Reorders the children of the containers of the `Drake` to match
+    /// `layout`, moving items between containers as needed
+    ///
+    /// Items that are not listed in `layout` are kept, after the listed
+    /// items of their container. Nothing is moved unless every id in
+    /// `layout` is found.
+    ///
+    /// # Errors
+    /// Returns [`OrderError::UnknownContainer`](OrderError::UnknownContainer)
+    /// or [`OrderError::UnknownItem`](OrderError::UnknownItem) for ids that
+    /// are not found among the containers of the `Drake` and their items,
+    /// and [`OrderError::DuplicateItem`](OrderError::DuplicateItem) for items
+    /// listed more than once
+    pub fn restore_order(&self, layout: &Layout) -> Result<(), OrderError> {
+        let attribute = layout.attribute.as_str();
+        let containers = self.container_elements();
+        let id = |el: &Element| el.get_attribute(attribute);
+        let items: Vec<Element> =
+            containers.iter().flat_map(children).collect();
+
+        let mut seen = HashSet::new();
+        let mut moves = Vec::with_capacity(layout.containers.len());
+        for (container_id, item_ids) in &layout.containers {
+            let container = containers
+                .iter()
+                .find(|el| id(el).as_ref() == Some(container_id))
+                .ok_or_else(|| {
+                    OrderError::UnknownContainer(container_id.clone())
+                })?;
+
+            let container_items = item_ids
+                .iter()
+                .map(|item_id| {
+                    if !seen.insert(item_id) {
+                        return Err(OrderError::DuplicateItem(item_id.clone()));
+                    }
+                    items
+                        .iter()
+                        .find(|el| id(el).as_ref() == Some(item_id))
+                        .ok_or_else(|| OrderError::UnknownItem(item_id.clone()))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            moves.push((container, container_items));
+        }
+
+        for (container, items) in moves {
+            // Inserting each item first, last to first, leaves them in order
+            // ahead of any item that is not listed
+            for item in items.into_iter().rev() {
+                let first = container.first_element_child();
+                insert_before(container, item, first.as_ref());
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use crate::event::*;
+use web_sys::{Element, Node};
+
+/// Where the dragged item was lifted from, recorded on `drag` before Dragula
+/// moves anything around
+pub(crate) struct Origin {
+    pub item: Element,
+    pub container: Element,
+    pub index: usize,
+    /// The element the item was placed before, or `None` if it was last
+    pub sibling: Option<Element>,
+}
+
+/// Follows a single drag from `drag` to `dragend`
+#[derive(Default)]
+pub(crate) struct DragState {
+    pub origin: Option<Origin>,
+    /// The copy being dragged instead of the item, when copying
+    pub copy: Option<Element>,
+}
+
+impl DragState {
+    /// Must be passed every event emitted by the `Drake`, in order
+    pub fn track(&mut self, event: &DragulaEvent<Element>) {
+        match event {
+            // Emitted right before `drag` when copying
+            DragulaEvent::Cloned(ClonedEvent {
+                clone,
+                kind: CloneKind::Copy,
+                ..
+            }) => self.copy = Some(clone.clone()),
+            DragulaEvent::Drag(DragEvent { el, source }) => {
+                self.origin = Some(Origin {
+                    item: el.clone(),
+                    container: source.clone(),
+                    index: index_of(el),
+                    sibling: el.next_element_sibling(),
+                });
+            }
+            DragulaEvent::DragEnd(_) => *self = DragState::default(),
+            _ => {}
+        }
+    }
+
+    /// Whether the drag is copying the item rather than moving it
+    pub fn copying(&self) -> bool {
+        self.copy.is_some()
+    }
+
+    /// Describes the drop of `el`, as it stands in the DOM right after the
+    /// `drop` event
+    pub fn indexed_drop(&self, el: &Element) -> Option<IndexedDropEvent> {
+        let origin = self.origin.as_ref()?;
+        let target = el.parent_element()?;
+        // Copying within the source container only reorders it, since
+        // Dragula removes the original in that case
+        let copy = self.copying() && target != origin.container;
+
+        Some(IndexedDropEvent {
+            el: el.clone(),
+            source: origin.container.clone(),
+            from_index: origin.index,
+            target,
+            to_index: index_of(el),
+            copy,
+        })
+    }
+}
+
+/// Index of `el` among the element children of its parent
+fn index_of(el: &Element) -> usize {
+    index_where(el, |_| true)
+}
+
+/// Index of `el` among the element children of its parent for which
+/// `counts` returns `true`
+pub(crate) fn index_where<F>(el: &Element, counts: F) -> usize
+where
+    F: Fn(&Element) -> bool,
+{
+    let mut index = 0;
+    let mut sibling = el.previous_element_sibling();
+    while let Some(el) = sibling {
+        if counts(&el) {
+            index += 1;
+        }
+        sibling = el.previous_element_sibling();
+    }
+    index
+}
+
+/// Places `el` in `container` before `sibling`, or last if `sibling` is
+/// `None` or no longer in `container`
+pub(crate) fn insert_before(
+    container: &Element,
+    el: &Element,
+    sibling: Option<&Element>,
+) {
+    let sibling = sibling
+        .filter(|sibling| sibling.parent_element().as_ref() == Some(container))
+        .map(AsRef::<Node>::as_ref);
+    // Inserting an element into a container cannot fail
+    let _ = container.insert_before(el, sibling);
+}
+
+/// Moves `el` so that it becomes the child of `container` at `index`
+pub(crate) fn insert_at(container: &Element, el: &Element, index: usize) {
+    el.remove();
+    let sibling = container.children().item(index as u32);
+    insert_before(container, el, sibling.as_ref());
+}
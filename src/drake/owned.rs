@@ -0,0 +1,82 @@
+use super::{Drake, ListenerHandle};
+use crate::closure::OwnedClosure;
+use std::ops::{Deref, DerefMut};
+
+/// A [`Drake`](crate::Drake) that is destroyed when dropped
+///
+/// Created by [`dragula_owned`](crate::dragula_owned). Unlike a plain
+/// `Drake`, the closures passed in through [`Options`](crate::Options) are
+/// not leaked. They are kept alive by the `OwnedDrake`, along with any
+/// listeners handed to [`keep`](OwnedDrake::keep), and freed once it is
+/// dropped, right after [`destroy`](crate::Drake::destroy) is called.
+///
+/// All of the methods on `Drake` are available through `Deref`.
+///
+/// Clones of the inner `Drake` must not be used once the `OwnedDrake` has
+/// been dropped, since the closures backing its options will have been
+/// freed.
+///
+/// ### Example:
+/// ```no_run
+/// use dragula::*;
+///
+/// let doc = web_sys::window().unwrap().document().unwrap();
+/// let element = doc.get_element_by_id("drag-container").unwrap();
+///
+/// {
+///     let mut drake = dragula_owned(&[element], Options::default());
+///
+///     let listener = drake.on_drop(|el, target, source, sibling| {
+///         //--snip--
+///     });
+///     drake.keep(listener);
+///
+///     //--snip--
+/// }
+/// // The drake has been destroyed, and its closures freed
+/// ```
+pub struct OwnedDrake {
+    drake: Drake,
+    listeners: Vec<ListenerHandle>,
+    closures: Vec<OwnedClosure>,
+}
+
+impl OwnedDrake {
+    pub(crate) fn new(drake: Drake, closures: Vec<OwnedClosure>) -> Self {
+        OwnedDrake {
+            drake,
+            listeners: Vec::new(),
+            closures,
+        }
+    }
+
+    /// Keeps `listener` registered until this `OwnedDrake` is dropped
+    pub fn keep(&mut self, listener: ListenerHandle) {
+        self.listeners.push(listener);
+    }
+}
+
+impl Deref for OwnedDrake {
+    type Target = Drake;
+
+    fn deref(&self) -> &Drake {
+        &self.drake
+    }
+}
+
+impl DerefMut for OwnedDrake {
+    fn deref_mut(&mut self) -> &mut Drake {
+        &mut self.drake
+    }
+}
+
+impl Drop for OwnedDrake {
+    fn drop(&mut self) {
+        // Kept listeners still receive the events fired by cancelling an
+        // ongoing drag
+        self.drake.destroy();
+
+        self.listeners.clear();
+        self.closures.clear();
+    }
+}
@@ -0,0 +1,271 @@
+use super::{Drake, ListenerHandle};
+use crate::event::*;
+use futures_channel::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use futures_core::Stream;
+use std::future::{self, Future};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A [`Stream`](futures_core::Stream) of events emitted by a
+/// [`Drake`](crate::Drake)
+///
+/// Created by the stream methods on `Drake`, such as
+/// [`drops`](crate::Drake::drops) or [`events`](crate::Drake::events). The
+/// stream ends once [`destroy`](crate::Drake::destroy) is called, after any
+/// events that were already emitted have been yielded. Dropping the stream
+/// removes its listeners from the `Drake`.
+///
+/// Only `Drake::destroy`, which dropping an
+/// [`OwnedDrake`](crate::OwnedDrake) also calls, ends the stream. Dragula
+/// emits no event of its own when it is destroyed, so a drake destroyed from
+/// JavaScript leaves the stream waiting forever. Drop the stream in that case.
+///
+/// Requires that feature `futures` be turned on
+pub struct EventStream<T> {
+    receiver: UnboundedReceiver<T>,
+    _listeners: ListenerHandle,
+}
+
+impl<T> Stream for EventStream<T> {
+    type Item = T;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<T>> {
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }
+}
+
+fn send<T>(sender: &UnboundedSender<T>, event: T) {
+    // The receiver can only be gone while the stream is being dropped
+    let _ = sender.unbounded_send(event);
+}
+
+/// Event streams
+///
+/// Requires that feature `futures` be turned on
+impl Drake {
+    fn stream<T, F>(&mut self, subscribe: F) -> EventStream<T>
+    where
+        T: 'static,
+        F: FnOnce(&mut Drake, UnboundedSender<T>) -> ListenerHandle,
+    {
+        let (sender, receiver) = mpsc::unbounded();
+
+        let closer = sender.clone();
+        let destroy = self.on_destroy(move || closer.close_channel());
+        let listeners = subscribe(self, sender).join(destroy);
+
+        EventStream {
+            receiver,
+            _listeners: listeners,
+        }
+    }
+
+    /// Returns a stream of every event emitted by the `Drake`, as passed to
+    /// [`on_event`](Drake::on_event).
+    ///
+    /// ### Example:
+    /// ```no_run
+    /// use dragula::*;
+    /// use futures::StreamExt;
+    /// # use wasm_bindgen::JsValue;
+    ///
+    /// # async fn run() {
+    /// # let element = JsValue::TRUE;
+    /// //--snip--
+    ///
+    /// let mut drake = dragula(&[element]);
+    /// let mut events = drake.events();
+    ///
+    /// while let Some(event) = events.next().await {
+    ///     //--snip--
+    /// }
+    /// // The drake was destroyed
+    /// # }
+    /// ```
+    pub fn events(&mut self) -> EventStream<DragulaEvent> {
+        self.stream(|drake, sender| {
+            drake.on_event(move |event| send(&sender, event))
+        })
+    }
+
+    /// Returns a stream of `drag` events.
+    /// See [`on_drag`](Drake::on_drag).
+    pub fn drags(&mut self) -> EventStream<DragEvent> {
+        self.stream(|drake, sender| {
+            drake.on_drag(move |el, source| {
+                send(&sender, DragEvent { el, source })
+            })
+        })
+    }
+
+    /// Returns a stream of `dragend` events.
+    /// See [`on_dragend`](Drake::on_dragend).
+    pub fn dragends(&mut self) -> EventStream<DragEndEvent> {
+        self.stream(|drake, sender| {
+            drake.on_dragend(move |el| send(&sender, DragEndEvent { el }))
+        })
+    }
+
+    /// Returns a stream of `drop` events.
+    /// See [`on_drop`](Drake::on_drop).
+    pub fn drops(&mut self) -> EventStream<DropEvent> {
+        self.stream(|drake, sender| {
+            drake.on_drop(move |el, target, source, sib| {
+                send(
+                    &sender,
+                    DropEvent {
+                        el,
+                        target,
+                        source,
+                        sibling: sibling(sib),
+                    },
+                )
+            })
+        })
+    }
+
+    /// Returns a stream of `cancel` events.
+    /// See [`on_cancel`](Drake::on_cancel).
+    pub fn cancels(&mut self) -> EventStream<CancelEvent> {
+        self.stream(|drake, sender| {
+            drake.on_cancel(move |el, container, source| {
+                send(
+                    &sender,
+                    CancelEvent {
+                        el,
+                        container,
+                        source,
+                    },
+                )
+            })
+        })
+    }
+
+    /// Returns a stream of `remove` events.
+    /// See [`on_remove`](Drake::on_remove).
+    pub fn removes(&mut self) -> EventStream<RemoveEvent> {
+        self.stream(|drake, sender| {
+            drake.on_remove(move |el, container, source| {
+                send(
+                    &sender,
+                    RemoveEvent {
+                        el,
+                        container,
+                        source,
+                    },
+                )
+            })
+        })
+    }
+
+    /// Returns a stream of `shadow` events.
+    /// See [`on_shadow`](Drake::on_shadow).
+    pub fn shadows(&mut self) -> EventStream<ShadowEvent> {
+        self.stream(|drake, sender| {
+            drake.on_shadow(move |el, container, source| {
+                send(
+                    &sender,
+                    ShadowEvent {
+                        el,
+                        container,
+                        source,
+                    },
+                )
+            })
+        })
+    }
+
+    /// Returns a stream of `over` events.
+    /// See [`on_over`](Drake::on_over).
+    pub fn overs(&mut self) -> EventStream<OverEvent> {
+        self.stream(|drake, sender| {
+            drake.on_over(move |el, container, source| {
+                send(
+                    &sender,
+                    OverEvent {
+                        el,
+                        container,
+                        source,
+                    },
+                )
+            })
+        })
+    }
+
+    /// Returns a stream of `out` events.
+    /// See [`on_out`](Drake::on_out).
+    pub fn outs(&mut self) -> EventStream<OutEvent> {
+        self.stream(|drake, sender| {
+            drake.on_out(move |el, container, source| {
+                send(
+                    &sender,
+                    OutEvent {
+                        el,
+                        container,
+                        source,
+                    },
+                )
+            })
+        })
+    }
+
+    /// Returns a stream of `cloned` events.
+    /// See [`on_cloned`](Drake::on_cloned).
+    pub fn clones(&mut self) -> EventStream<ClonedEvent> {
+        self.stream(|drake, sender| {
+            drake.on_cloned(move |clone, original, kind| {
+                send(
+                    &sender,
+                    ClonedEvent {
+                        clone,
+                        original,
+                        kind: CloneKind::from(kind),
+                    },
+                )
+            })
+        })
+    }
+
+    /// Returns a stream of drops that carry the indices involved.
+    /// See [`on_indexed_drop`](Drake::on_indexed_drop).
+    ///
+    /// Requires that feature `web-sys` be turned on
+    #[cfg(feature = "web-sys")]
+    pub fn indexed_drops(&mut self) -> EventStream<IndexedDropEvent> {
+        self.stream(|drake, sender| {
+            drake.on_indexed_drop(move |drop| send(&sender, drop))
+        })
+    }
+
+    /// Resolves with the next `drop` event, or `None` if the `Drake` is
+    /// destroyed first. Listening starts as soon as this is called, rather
+    /// than when the future is first polled.
+    ///
+    /// Like an [`EventStream`](EventStream), the future never resolves if
+    /// the drake is destroyed from JavaScript rather than through
+    /// [`destroy`](Drake::destroy).
+    ///
+    /// ### Example:
+    /// ```no_run
+    /// use dragula::*;
+    /// # use wasm_bindgen::JsValue;
+    ///
+    /// # async fn run() {
+    /// # let element = JsValue::TRUE;
+    /// //--snip--
+    ///
+    /// let mut drake = dragula(&[element]);
+    ///
+    /// if let Some(drop) = drake.next_drop().await {
+    ///     // Move on to the next step
+    /// }
+    /// # }
+    /// ```
+    pub fn next_drop(&mut self) -> impl Future<Output = Option<DropEvent>> {
+        let mut drops = self.drops();
+        future::poll_fn(move |cx| Pin::new(&mut drops).poll_next(cx))
+    }
+}
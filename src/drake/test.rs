@@ -1,8 +1,10 @@
 use super::*;
-use crate::dragula;
 use crate::test_utils::*;
+use crate::{dragula, dragula_owned, Options};
+use std::cell::RefCell;
+use std::rc::Rc;
 use wasm_bindgen_test::*;
-use web_sys::*;
+use web_sys::Element;
 
 wasm_bindgen_test_configure!(run_in_browser);
 
@@ -78,7 +80,6 @@ fn set_containers_after() {
 }
 
 #[wasm_bindgen_test]
-#[cfg(feature = "js-sys")]
 fn add_containers_individually() {
     console_error_panic_hook::set_once();
 
@@ -114,6 +115,90 @@ fn add_containers_individually() {
     }
 }
 
+#[wasm_bindgen_test]
+fn remove_container_keeps_others() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(3, 0);
+
+    let test = |element: &Element| {
+        let containers = element.children();
+        let mut in_containers: Vec<_> = (0..containers.length())
+            .map(|i| containers.item(i))
+            .map(Option::unwrap)
+            .collect();
+
+        let mut drake = dragula(&in_containers);
+
+        let removed = in_containers.remove(1);
+        drake.remove_container(&removed);
+
+        let out_containers: Vec<_> = drake
+            .containers()
+            .iter()
+            .cloned()
+            .map(Element::from)
+            .collect();
+
+        assert_eq!(in_containers, out_containers);
+        assert!(!drake.has_container(&removed));
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn has_container_checks_is_container() {
+    use crate::dragula_options;
+
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(3, 0);
+
+    let test = |element: &Element| {
+        let containers = element.children();
+        let listed = containers.item(0).unwrap();
+        let matched = containers.item(1).unwrap();
+        let neither = containers.item(2).unwrap();
+
+        let matched_ref = matched.clone();
+        let options = Options {
+            is_container: Box::new(move |el| el == JsValue::from(&matched_ref)),
+            ..Options::default()
+        };
+        let drake = dragula_options(std::slice::from_ref(&listed), options);
+
+        assert!(drake.has_container(&listed));
+        assert!(drake.has_container(&matched));
+        assert!(!drake.has_container(&neither));
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+#[cfg(feature = "web-sys")]
+fn container_of_finds_closest_container() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 2);
+
+    let test = |element: &Element| {
+        let containers = vec![element.last_element_child().unwrap()];
+        let drake = dragula(&containers);
+
+        let container = containers.first().unwrap();
+        let item = container.first_element_child().unwrap();
+        let outside = element.first_element_child().unwrap();
+        let outside_item = outside.first_element_child().unwrap();
+
+        assert_eq!(drake.container_of(&item), Some(container.clone()));
+        assert_eq!(drake.container_of(&outside_item), None);
+    };
+
+    run_dom_test(test, &html);
+}
+
 #[wasm_bindgen_test]
 fn dragging_off_by_default() {
     let in_containers: Vec<JsValue> = vec![];
@@ -253,7 +338,7 @@ fn remove_makes_dragging_false() {
         drake.remove();
 
         let dragging = drake.dragging();
-        assert_eq!(dragging, false);
+        assert!(!dragging);
     };
 
     run_dom_test(test, &html);
@@ -284,7 +369,7 @@ fn cancel_triggers_callback() {
             .unwrap();
 
         let item_ref = item.clone();
-        drake.on_cancel(move |_, _, _| {
+        let _listener = drake.on_cancel(move |_, _, _| {
             item_ref
                 .set_attribute(CANCEL_ATTR, TRUE)
                 .expect("Unable to set cancelled attribute");
@@ -328,7 +413,7 @@ fn cancel_with_revert_triggers_callback() {
             .unwrap();
 
         let item_ref = item.clone();
-        drake.on_cancel(move |_, _, _| {
+        let _listener = drake.on_cancel(move |_, _, _| {
             item_ref
                 .set_attribute(CANCEL_ATTR, TRUE)
                 .expect("Unable to set cancelled attribute");
@@ -437,3 +522,870 @@ fn destroy_makes_dragging_false() {
 
     run_dom_test(test, &html);
 }
+
+#[wasm_bindgen_test]
+fn on_event_receives_events_in_order() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 3);
+
+    let test = |element: &Element| {
+        let containers = element.children();
+        let in_containers: Vec<_> = (0..containers.length())
+            .map(|i| containers.item(i))
+            .map(Option::unwrap)
+            .collect();
+
+        let mut drake = dragula(&in_containers);
+
+        let item = in_containers
+            .first()
+            .unwrap()
+            .first_element_child()
+            .unwrap();
+
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let events_ref = events.clone();
+        let _listener = drake.on_event(move |event| {
+            events_ref
+                .borrow_mut()
+                .push(event.unchecked_into::<Element>());
+        });
+
+        drake.start(&item);
+        drake.cancel();
+
+        let source = in_containers.first().unwrap().clone();
+        let expected = vec![
+            DragulaEvent::Drag(DragEvent {
+                el: item.clone(),
+                source: source.clone(),
+            }),
+            DragulaEvent::Cancel(CancelEvent {
+                el: item.clone(),
+                container: source.clone(),
+                source,
+            }),
+            DragulaEvent::DragEnd(DragEndEvent { el: item }),
+        ];
+
+        assert_eq!(*events.borrow(), expected);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn on_event_queues_reentrant_events() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 3);
+
+    let test = |element: &Element| {
+        let containers = element.children();
+        let in_containers: Vec<_> = (0..containers.length())
+            .map(|i| containers.item(i))
+            .map(Option::unwrap)
+            .collect();
+
+        let mut drake = dragula(&in_containers);
+
+        let item = in_containers
+            .first()
+            .unwrap()
+            .first_element_child()
+            .unwrap();
+
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let events_ref = events.clone();
+        let drake_ref = drake.clone();
+        let _listener = drake.on_event(move |event| {
+            if let DragulaEvent::Drag(_) = event {
+                // Emits `cancel` and `dragend` while `drag` is handled
+                drake_ref.cancel();
+            }
+            events_ref.borrow_mut().push(event);
+        });
+
+        drake.start(&item);
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[0], DragulaEvent::Drag(_)));
+        assert!(matches!(events[1], DragulaEvent::Cancel(_)));
+        assert!(matches!(events[2], DragulaEvent::DragEnd(_)));
+        assert!(!drake.dragging());
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn unsubscribed_listener_is_not_called() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 3);
+
+    let test = |element: &Element| {
+        let containers = element.children();
+        let in_containers: Vec<_> = (0..containers.length())
+            .map(|i| containers.item(i))
+            .map(Option::unwrap)
+            .collect();
+
+        let mut drake = dragula(&in_containers);
+
+        let item = in_containers
+            .first()
+            .unwrap()
+            .first_element_child()
+            .unwrap();
+
+        let drags = Rc::new(RefCell::new(0));
+        let drags_ref = drags.clone();
+        let listener = drake.on_drag(move |_, _| {
+            *drags_ref.borrow_mut() += 1;
+        });
+
+        drake.start(&item);
+        drake.cancel();
+        assert_eq!(*drags.borrow(), 1);
+
+        listener.unsubscribe();
+
+        drake.start(&item);
+        drake.cancel();
+        assert_eq!(*drags.borrow(), 1);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn unsubscribing_keeps_other_listeners() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 3);
+
+    let test = |element: &Element| {
+        let containers = element.children();
+        let in_containers: Vec<_> = (0..containers.length())
+            .map(|i| containers.item(i))
+            .map(Option::unwrap)
+            .collect();
+
+        let mut drake = dragula(&in_containers);
+
+        let item = in_containers
+            .first()
+            .unwrap()
+            .first_element_child()
+            .unwrap();
+
+        let first = Rc::new(RefCell::new(0));
+        let first_ref = first.clone();
+        let first_listener = drake.on_drag(move |_, _| {
+            *first_ref.borrow_mut() += 1;
+        });
+
+        let second = Rc::new(RefCell::new(0));
+        let second_ref = second.clone();
+        let _second_listener = drake.on_drag(move |_, _| {
+            *second_ref.borrow_mut() += 1;
+        });
+
+        drop(first_listener);
+
+        drake.start(&item);
+        drake.cancel();
+
+        assert_eq!(*first.borrow(), 0);
+        assert_eq!(*second.borrow(), 1);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn forgotten_listener_is_still_called() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 3);
+
+    let test = |element: &Element| {
+        let containers = element.children();
+        let in_containers: Vec<_> = (0..containers.length())
+            .map(|i| containers.item(i))
+            .map(Option::unwrap)
+            .collect();
+
+        let mut drake = dragula(&in_containers);
+
+        let item = in_containers
+            .first()
+            .unwrap()
+            .first_element_child()
+            .unwrap();
+
+        let drags = Rc::new(RefCell::new(0));
+        let drags_ref = drags.clone();
+        drake
+            .on_drag(move |_, _| {
+                *drags_ref.borrow_mut() += 1;
+            })
+            .forget();
+
+        drake.start(&item);
+        drake.cancel();
+
+        assert_eq!(*drags.borrow(), 1);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn dropping_owned_drake_destroys_it() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 3);
+
+    let test = |element: &Element| {
+        let containers = element.children();
+        let in_containers: Vec<_> = (0..containers.length())
+            .map(|i| containers.item(i))
+            .map(Option::unwrap)
+            .collect();
+
+        let mut owned = dragula_owned(&in_containers, Options::default());
+        let drake = Drake::clone(&owned);
+
+        let item = in_containers
+            .first()
+            .unwrap()
+            .first_element_child()
+            .unwrap();
+
+        owned.start(&item);
+        assert!(drake.dragging());
+
+        drop(owned);
+
+        assert!(!drake.dragging());
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn owned_drake_keeps_listeners_until_dropped() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 3);
+
+    let test = |element: &Element| {
+        let containers = element.children();
+        let in_containers: Vec<_> = (0..containers.length())
+            .map(|i| containers.item(i))
+            .map(Option::unwrap)
+            .collect();
+
+        let mut owned = dragula_owned(&in_containers, Options::default());
+        let mut drake = Drake::clone(&owned);
+
+        let item = in_containers
+            .first()
+            .unwrap()
+            .first_element_child()
+            .unwrap();
+
+        let cancels = Rc::new(RefCell::new(0));
+        let cancels_ref = cancels.clone();
+        let listener = owned.on_cancel(move |_, _, _| {
+            *cancels_ref.borrow_mut() += 1;
+        });
+        owned.keep(listener);
+
+        owned.start(&item);
+
+        // Destroying mid-drag cancels it, which kept listeners still observe
+        drop(owned);
+        assert_eq!(*cancels.borrow(), 1);
+
+        drake.start(&item);
+        drake.cancel();
+        assert_eq!(*cancels.borrow(), 1);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+#[cfg(feature = "web-sys")]
+fn container_elements_match_containers() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(3, 0);
+
+    let test = |element: &Element| {
+        let containers = element.children();
+        let in_containers: Vec<_> = (0..containers.length())
+            .map(|i| containers.item(i))
+            .map(Option::unwrap)
+            .collect();
+
+        let drake = dragula(&in_containers);
+
+        assert_eq!(in_containers, drake.container_elements());
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+#[cfg(feature = "web-sys")]
+fn on_element_event_receives_elements() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 3);
+
+    let test = |element: &Element| {
+        let containers = element.children();
+        let in_containers: Vec<_> = (0..containers.length())
+            .map(|i| containers.item(i))
+            .map(Option::unwrap)
+            .collect();
+
+        let mut drake = dragula(&in_containers);
+
+        let source = in_containers.first().unwrap().clone();
+        let item = source.first_element_child().unwrap();
+
+        let drags = Rc::new(RefCell::new(Vec::new()));
+        let drags_ref = drags.clone();
+        let _listener = drake.on_element_event(move |event| {
+            if let DragulaEvent::Drag(drag) = event {
+                drags_ref.borrow_mut().push(drag);
+            }
+        });
+
+        drake.start(&item);
+        drake.cancel();
+
+        let expected = vec![DragEvent { el: item, source }];
+        assert_eq!(*drags.borrow(), expected);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn destroy_triggers_on_destroy_callback() {
+    let in_containers: Vec<JsValue> = vec![];
+    let mut drake = dragula(&in_containers);
+
+    let destroyed = Rc::new(RefCell::new(false));
+    let destroyed_ref = destroyed.clone();
+    let _listener = drake.on_destroy(move || {
+        *destroyed_ref.borrow_mut() = true;
+    });
+
+    drake.destroy();
+
+    assert!(*destroyed.borrow());
+}
+
+#[wasm_bindgen_test]
+#[cfg(feature = "futures")]
+async fn event_stream_ends_on_destroy() {
+    use futures::StreamExt;
+
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 3);
+
+    let test = |element: &Element| {
+        let containers = element.children();
+        let in_containers: Vec<_> = (0..containers.length())
+            .map(|i| containers.item(i))
+            .map(Option::unwrap)
+            .collect();
+
+        let mut drake = dragula(&in_containers);
+        let events = drake.events();
+
+        let item = in_containers
+            .first()
+            .unwrap()
+            .first_element_child()
+            .unwrap();
+
+        drake.start(&item);
+        drake.cancel();
+        drake.destroy();
+
+        events
+    };
+
+    let events: Vec<_> = run_dom_test(test, &html).collect().await;
+
+    assert_eq!(events.len(), 3);
+    assert!(matches!(events[0], DragulaEvent::Drag(_)));
+    assert!(matches!(events[1], DragulaEvent::Cancel(_)));
+    assert!(matches!(events[2], DragulaEvent::DragEnd(_)));
+}
+
+#[wasm_bindgen_test]
+#[cfg(feature = "futures")]
+async fn next_drop_resolves_on_drop() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 3);
+
+    let test = |element: &Element| {
+        let containers = element.children();
+        let in_containers: Vec<_> = (0..containers.length())
+            .map(|i| containers.item(i))
+            .map(Option::unwrap)
+            .collect();
+
+        let mut drake = dragula(&in_containers);
+        let next_drop = drake.next_drop();
+
+        let source = in_containers.first().unwrap();
+        let target = in_containers.last().unwrap();
+        let item = source.first_element_child().unwrap();
+
+        // Move the item as the shadow would have, so ending the drag drops it
+        drake.start(&item);
+        target.append_child(&item).unwrap();
+        drake.end();
+
+        (next_drop, item, target.clone())
+    };
+
+    let (next_drop, item, target) = run_dom_test(test, &html);
+    let drop = next_drop.await.expect("Drake was not dropped");
+
+    assert_eq!(drop.el, JsValue::from(item));
+    assert_eq!(drop.target, JsValue::from(target));
+}
+
+#[wasm_bindgen_test]
+#[cfg(feature = "futures")]
+async fn next_drop_resolves_none_on_destroy() {
+    let in_containers: Vec<JsValue> = vec![];
+    let mut drake = dragula(&in_containers);
+
+    let next_drop = drake.next_drop();
+    drake.destroy();
+
+    assert_eq!(next_drop.await, None);
+}
+
+#[wasm_bindgen_test]
+#[cfg(feature = "web-sys")]
+fn controlled_reverts_drop_and_reports_intent() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 3);
+
+    let test = |element: &Element| {
+        let containers = element.children();
+        let in_containers: Vec<_> = (0..containers.length())
+            .map(|i| containers.item(i))
+            .map(Option::unwrap)
+            .collect();
+
+        let mut drake = dragula(&in_containers);
+
+        let intents = Rc::new(RefCell::new(Vec::new()));
+        let intents_ref = intents.clone();
+        let _listener = drake.controlled(move |intent| {
+            intents_ref.borrow_mut().push(intent);
+        });
+
+        let source = in_containers.first().unwrap();
+        let target = in_containers.last().unwrap();
+        let item = source.children().item(1).unwrap();
+        let sibling = target.children().item(2).unwrap();
+
+        drake.start(&item);
+        target.insert_before(&item, Some(&sibling)).unwrap();
+        drake.end();
+
+        assert_eq!(source.children().item(1), Some(item.clone()));
+        assert_eq!(target.child_element_count(), 3);
+
+        let expected = vec![MoveIntent {
+            item,
+            from_container: source.clone(),
+            from_index: 1,
+            to_container: Some(target.clone()),
+            to_index: Some(2),
+            copy: false,
+        }];
+        assert_eq!(*intents.borrow(), expected);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+#[cfg(feature = "web-sys")]
+fn controlled_removes_dropped_copy() {
+    use crate::dragula_options;
+    use crate::options::CopyValue;
+
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 3);
+
+    let test = |element: &Element| {
+        let containers = element.children();
+        let in_containers: Vec<_> = (0..containers.length())
+            .map(|i| containers.item(i))
+            .map(Option::unwrap)
+            .collect();
+
+        let options = Options {
+            copy: CopyValue::Bool(true),
+            ..Options::default()
+        };
+        let mut drake = dragula_options(&in_containers, options);
+
+        let intents = Rc::new(RefCell::new(Vec::new()));
+        let intents_ref = intents.clone();
+        let _listener = drake.controlled(move |intent| {
+            intents_ref.borrow_mut().push(intent);
+        });
+
+        let source = in_containers.first().unwrap();
+        let target = in_containers.last().unwrap();
+        let item = source.first_element_child().unwrap();
+
+        // While copying, the shadow is the copy rather than the item
+        let copies = Rc::new(RefCell::new(Vec::new()));
+        let copies_ref = copies.clone();
+        let _cloned = drake.on_element_event(move |event| {
+            if let DragulaEvent::Cloned(cloned) = event {
+                copies_ref.borrow_mut().push(cloned.clone);
+            }
+        });
+
+        drake.start(&item);
+        let copy = copies.borrow().first().unwrap().clone();
+        target.append_child(&copy).unwrap();
+        drake.end();
+
+        assert_eq!(source.child_element_count(), 3);
+        assert_eq!(target.child_element_count(), 3);
+
+        let expected = vec![MoveIntent {
+            item,
+            from_container: source.clone(),
+            from_index: 0,
+            to_container: Some(target.clone()),
+            to_index: Some(3),
+            copy: true,
+        }];
+        assert_eq!(*intents.borrow(), expected);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+#[cfg(feature = "web-sys")]
+fn controlled_copy_within_source_reorders() {
+    use crate::dragula_options;
+    use crate::options::CopyValue;
+
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(1, 3);
+
+    let test = |element: &Element| {
+        let source = element.first_element_child().unwrap();
+        let options = Options {
+            copy: CopyValue::Bool(true),
+            copy_sort_source: true,
+            ..Options::default()
+        };
+        let mut drake = dragula_options(std::slice::from_ref(&source), options);
+
+        let intents = Rc::new(RefCell::new(Vec::new()));
+        let intents_ref = intents.clone();
+        let _listener = drake.controlled(move |intent| {
+            intents_ref.borrow_mut().push(intent);
+        });
+
+        let copies = Rc::new(RefCell::new(Vec::new()));
+        let copies_ref = copies.clone();
+        let _cloned = drake.on_element_event(move |event| {
+            if let DragulaEvent::Cloned(cloned) = event {
+                copies_ref.borrow_mut().push(cloned.clone);
+            }
+        });
+
+        let item = source.first_element_child().unwrap();
+        drake.start(&item);
+        let copy = copies.borrow().first().unwrap().clone();
+        source.append_child(&copy).unwrap();
+        drake.end();
+
+        // The original is back in place, and the copy is gone
+        assert_eq!(source.child_element_count(), 3);
+        assert_eq!(source.first_element_child(), Some(item.clone()));
+        assert!(copy.parent_element().is_none());
+
+        let expected = vec![MoveIntent {
+            item,
+            from_container: source.clone(),
+            from_index: 0,
+            to_container: Some(source.clone()),
+            to_index: Some(2),
+            copy: false,
+        }];
+        assert_eq!(*intents.borrow(), expected);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+#[cfg(feature = "web-sys")]
+fn controlled_restores_removed_item() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(1, 3);
+
+    let test = |element: &Element| {
+        let containers = vec![element.first_element_child().unwrap()];
+        let mut drake = dragula(&containers);
+
+        let intents = Rc::new(RefCell::new(Vec::new()));
+        let intents_ref = intents.clone();
+        let _listener = drake.controlled(move |intent| {
+            intents_ref.borrow_mut().push(intent);
+        });
+
+        let source = containers.first().unwrap();
+        let item = source.children().item(2).unwrap();
+
+        drake.start(&item);
+        drake.remove();
+
+        assert_eq!(source.children().item(2), Some(item.clone()));
+
+        let intents = intents.borrow();
+        assert_eq!(intents.len(), 1);
+        assert!(intents[0].is_removal());
+        assert_eq!(intents[0].from_index, 2);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+#[cfg(feature = "web-sys")]
+fn indexed_drop_reports_indices() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 3);
+
+    let test = |element: &Element| {
+        let containers = element.children();
+        let in_containers: Vec<_> = (0..containers.length())
+            .map(|i| containers.item(i))
+            .map(Option::unwrap)
+            .collect();
+
+        let mut drake = dragula(&in_containers);
+
+        let drops = Rc::new(RefCell::new(Vec::new()));
+        let drops_ref = drops.clone();
+        let _listener = drake.on_indexed_drop(move |drop| {
+            drops_ref.borrow_mut().push(drop);
+        });
+
+        let source = in_containers.first().unwrap();
+        let target = in_containers.last().unwrap();
+        let item = source.children().item(2).unwrap();
+        let sibling = target.children().item(1).unwrap();
+
+        drake.start(&item);
+        target.insert_before(&item, Some(&sibling)).unwrap();
+        drake.end();
+
+        let expected = vec![IndexedDropEvent {
+            el: item,
+            source: source.clone(),
+            from_index: 2,
+            target: target.clone(),
+            to_index: 1,
+            copy: false,
+        }];
+        assert_eq!(*drops.borrow(), expected);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+#[cfg(feature = "web-sys")]
+fn indexed_drop_within_container() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(1, 4);
+
+    let test = |element: &Element| {
+        let containers = vec![element.first_element_child().unwrap()];
+        let mut drake = dragula(&containers);
+
+        let drops = Rc::new(RefCell::new(Vec::new()));
+        let drops_ref = drops.clone();
+        let _listener = drake.on_indexed_drop(move |drop| {
+            drops_ref
+                .borrow_mut()
+                .push((drop.from_index, drop.to_index));
+        });
+
+        let source = containers.first().unwrap();
+        let item = source.first_element_child().unwrap();
+
+        drake.start(&item);
+        source.append_child(&item).unwrap();
+        drake.end();
+
+        assert_eq!(*drops.borrow(), vec![(0, 3)]);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[cfg(feature = "web-sys")]
+fn strings(ids: &[&str]) -> Vec<String> {
+    ids.iter().map(|id| String::from(*id)).collect()
+}
+
+#[wasm_bindgen_test]
+#[cfg(feature = "web-sys")]
+fn serialize_order_lists_items() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 2);
+
+    let test = |element: &Element| {
+        let containers = with_ids(element);
+        let drake = dragula(&containers);
+
+        let layout = drake.serialize_order("data-id").unwrap();
+
+        assert_eq!(layout.attribute, "data-id");
+        assert_eq!(
+            layout.containers,
+            vec![
+                (String::from("cnt_0"), strings(&["drag_0_0", "drag_0_1"])),
+                (String::from("cnt_1"), strings(&["drag_1_0", "drag_1_1"])),
+            ]
+        );
+
+        containers[1].remove_attribute("data-id").unwrap();
+        assert_eq!(
+            drake.serialize_order("data-id"),
+            Err(OrderError::MissingId(containers[1].clone()))
+        );
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+#[cfg(feature = "web-sys")]
+fn restore_order_moves_items() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 2);
+
+    let test = |element: &Element| {
+        let containers = with_ids(element);
+        let drake = dragula(&containers);
+
+        let layout = Layout {
+            attribute: String::from("data-id"),
+            containers: vec![
+                (String::from("cnt_0"), strings(&["drag_1_1", "drag_0_0"])),
+                (String::from("cnt_1"), strings(&["drag_0_1"])),
+            ],
+        };
+        drake.restore_order(&layout).unwrap();
+
+        let order = drake.serialize_order("data-id").unwrap();
+        assert_eq!(
+            order.containers,
+            vec![
+                (String::from("cnt_0"), strings(&["drag_1_1", "drag_0_0"])),
+                (String::from("cnt_1"), strings(&["drag_0_1", "drag_1_0"])),
+            ]
+        );
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+#[cfg(feature = "web-sys")]
+fn restore_order_reports_unknown_ids() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 2);
+
+    let test = |element: &Element| {
+        let containers = with_ids(element);
+        let drake = dragula(&containers);
+        let before = drake.serialize_order("data-id").unwrap();
+
+        let restore = |containers: Vec<(&str, &[&str])>| {
+            drake.restore_order(&Layout {
+                attribute: String::from("data-id"),
+                containers: containers
+                    .into_iter()
+                    .map(|(id, items)| (String::from(id), strings(items)))
+                    .collect(),
+            })
+        };
+
+        assert_eq!(
+            restore(vec![("cnt_0", &["drag_0_1"]), ("cnt_9", &[])]),
+            Err(OrderError::UnknownContainer(String::from("cnt_9")))
+        );
+        assert_eq!(
+            restore(vec![("cnt_1", &["drag_0_0", "drag_9_9"])]),
+            Err(OrderError::UnknownItem(String::from("drag_9_9")))
+        );
+        assert_eq!(
+            restore(vec![("cnt_0", &["drag_1_0"]), ("cnt_1", &["drag_1_0"])]),
+            Err(OrderError::DuplicateItem(String::from("drag_1_0")))
+        );
+
+        // Nothing moves when an id is unknown
+        assert_eq!(drake.serialize_order("data-id").unwrap(), before);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+#[cfg(feature = "serde")]
+fn layout_round_trips_through_serde() {
+    let layout = Layout {
+        attribute: String::from("data-id"),
+        containers: vec![(String::from("todo"), strings(&["a", "b"]))],
+    };
+
+    let json = serde_json::to_string(&layout).unwrap();
+    let restored: Layout = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(restored, layout);
+}
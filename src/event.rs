@@ -0,0 +1,298 @@
+//! Typed representations of the events emitted by a [`Drake`](crate::Drake)
+//!
+//! Every event carries the DOM elements involved as `T`, which defaults to
+//! [`JsValue`]. If you would rather work with a concrete type such as
+//! `web_sys::Element`, call
+//! [`DragulaEvent::unchecked_into`](DragulaEvent::unchecked_into) on the
+//! received event.
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+#[cfg(feature = "web-sys")]
+use web_sys::Element;
+
+/// The reason an element was cloned, as reported by the `cloned` event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloneKind {
+    /// The clone is the mirror image that follows the pointer while dragging
+    Mirror,
+    /// The clone is the copy that will be dropped, because
+    /// [`copy`](crate::Options::copy) is enabled
+    Copy,
+}
+
+impl From<JsValue> for CloneKind {
+    fn from(kind: JsValue) -> CloneKind {
+        const MIRROR: &str = "mirror";
+
+        match kind.as_string().as_deref() {
+            Some(MIRROR) => CloneKind::Mirror,
+            _ => CloneKind::Copy,
+        }
+    }
+}
+
+/// `el` was lifted from `source`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DragEvent<T = JsValue> {
+    pub el: T,
+    pub source: T,
+}
+
+/// Dragging `el` ended with either `cancel`, `remove`, or `drop`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DragEndEvent<T = JsValue> {
+    pub el: T,
+}
+
+/// `el` was dropped into `target` before `sibling`, and originally came from
+/// `source`. `sibling` is `None` when `el` was placed as the last child of
+/// `target`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropEvent<T = JsValue> {
+    pub el: T,
+    pub target: T,
+    pub source: T,
+    pub sibling: Option<T>,
+}
+
+/// `el` was being dragged but it got nowhere and went back into `container`,
+/// its last stable parent; `el` originally came from `source`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CancelEvent<T = JsValue> {
+    pub el: T,
+    pub container: T,
+    pub source: T,
+}
+
+/// `el` was being dragged but it got nowhere and it was removed from the DOM.
+/// Its last stable parent was `container`, and originally came from `source`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoveEvent<T = JsValue> {
+    pub el: T,
+    pub container: T,
+    pub source: T,
+}
+
+/// `el`, _the visual aid shadow_, was moved into `container`; `el` originally
+/// came from `source`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShadowEvent<T = JsValue> {
+    pub el: T,
+    pub container: T,
+    pub source: T,
+}
+
+/// `el` is over `container`, and originally came from `source`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverEvent<T = JsValue> {
+    pub el: T,
+    pub container: T,
+    pub source: T,
+}
+
+/// `el` was dragged out of `container` or dropped, and originally came from
+/// `source`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutEvent<T = JsValue> {
+    pub el: T,
+    pub container: T,
+    pub source: T,
+}
+
+/// DOM element `original` was cloned as `clone`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClonedEvent<T = JsValue> {
+    pub clone: T,
+    pub original: T,
+    pub kind: CloneKind,
+}
+
+/// `el` was dropped into `target` at `to_index`, having been lifted from
+/// `source` at `from_index`.
+///
+/// Indices count the element children of a container. `from_index` is
+/// recorded when the drag starts, so it is unaffected by the changes Dragula
+/// makes to the DOM while dragging. When `copy` is `true`, `el` is the copy
+/// that was dropped, and the original is still in `source`. Copying within
+/// `source` only reorders it, since Dragula removes the original, so `copy`
+/// is `false` in that case.
+///
+/// Passed to the listener registered with
+/// [`Drake::on_indexed_drop`](crate::Drake::on_indexed_drop).
+///
+/// Requires that feature `web-sys` be turned on
+#[cfg(feature = "web-sys")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexedDropEvent {
+    pub el: Element,
+    pub source: Element,
+    pub from_index: usize,
+    pub target: Element,
+    pub to_index: usize,
+    pub copy: bool,
+}
+
+/// Any of the events emitted by a [`Drake`](crate::Drake)
+///
+/// Passed to the listener registered with
+/// [`Drake::on_event`](crate::Drake::on_event), so that a single `match` can
+/// handle every event.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DragulaEvent<T = JsValue> {
+    Drag(DragEvent<T>),
+    DragEnd(DragEndEvent<T>),
+    Drop(DropEvent<T>),
+    Cancel(CancelEvent<T>),
+    Remove(RemoveEvent<T>),
+    Shadow(ShadowEvent<T>),
+    Over(OverEvent<T>),
+    Out(OutEvent<T>),
+    Cloned(ClonedEvent<T>),
+}
+
+impl<T> DragulaEvent<T>
+where
+    T: JsCast,
+{
+    /// Casts the elements carried by this event to `U` without any runtime
+    /// checks, in the same way as
+    /// [`JsCast::unchecked_into`](wasm_bindgen::JsCast::unchecked_into).
+    ///
+    /// Dragula only ever reports DOM elements, so casting to
+    /// `web_sys::Element` is always sound.
+    pub fn unchecked_into<U>(self) -> DragulaEvent<U>
+    where
+        U: JsCast,
+    {
+        let cast = |val: T| val.unchecked_into::<U>();
+
+        match self {
+            DragulaEvent::Drag(DragEvent { el, source }) => {
+                DragulaEvent::Drag(DragEvent {
+                    el: cast(el),
+                    source: cast(source),
+                })
+            }
+            DragulaEvent::DragEnd(DragEndEvent { el }) => {
+                DragulaEvent::DragEnd(DragEndEvent { el: cast(el) })
+            }
+            DragulaEvent::Drop(DropEvent {
+                el,
+                target,
+                source,
+                sibling,
+            }) => DragulaEvent::Drop(DropEvent {
+                el: cast(el),
+                target: cast(target),
+                source: cast(source),
+                sibling: sibling.map(cast),
+            }),
+            DragulaEvent::Cancel(CancelEvent {
+                el,
+                container,
+                source,
+            }) => DragulaEvent::Cancel(CancelEvent {
+                el: cast(el),
+                container: cast(container),
+                source: cast(source),
+            }),
+            DragulaEvent::Remove(RemoveEvent {
+                el,
+                container,
+                source,
+            }) => DragulaEvent::Remove(RemoveEvent {
+                el: cast(el),
+                container: cast(container),
+                source: cast(source),
+            }),
+            DragulaEvent::Shadow(ShadowEvent {
+                el,
+                container,
+                source,
+            }) => DragulaEvent::Shadow(ShadowEvent {
+                el: cast(el),
+                container: cast(container),
+                source: cast(source),
+            }),
+            DragulaEvent::Over(OverEvent {
+                el,
+                container,
+                source,
+            }) => DragulaEvent::Over(OverEvent {
+                el: cast(el),
+                container: cast(container),
+                source: cast(source),
+            }),
+            DragulaEvent::Out(OutEvent {
+                el,
+                container,
+                source,
+            }) => DragulaEvent::Out(OutEvent {
+                el: cast(el),
+                container: cast(container),
+                source: cast(source),
+            }),
+            DragulaEvent::Cloned(ClonedEvent {
+                clone,
+                original,
+                kind,
+            }) => DragulaEvent::Cloned(ClonedEvent {
+                clone: cast(clone),
+                original: cast(original),
+                kind,
+            }),
+        }
+    }
+}
+
+/// Converts a possibly `null` sibling argument into an `Option`
+pub(crate) fn sibling(sibling: JsValue) -> Option<JsValue> {
+    if sibling.is_null() || sibling.is_undefined() {
+        None
+    } else {
+        Some(sibling)
+    }
+}
+
+/// Shares a single listener between several JS callbacks
+///
+/// Dragula emits events synchronously, so a listener that calls back into
+/// the `Drake` (for example, calling `cancel` while handling `drag`) would
+/// otherwise be re-entered while it is still running. Such events are queued
+/// and delivered once the running call returns.
+pub(crate) struct Dispatcher<E> {
+    listener: RefCell<Box<dyn FnMut(E)>>,
+    queue: RefCell<VecDeque<E>>,
+}
+
+impl<E> Dispatcher<E> {
+    pub fn new<F>(listener: F) -> Self
+    where
+        F: FnMut(E) + 'static,
+    {
+        Dispatcher {
+            listener: RefCell::new(Box::new(listener)),
+            queue: RefCell::new(VecDeque::new()),
+        }
+    }
+
+    pub fn dispatch(&self, event: E) {
+        self.queue.borrow_mut().push_back(event);
+
+        let mut listener = match self.listener.try_borrow_mut() {
+            Ok(listener) => listener,
+            // Already dispatching further up the stack
+            Err(_) => return,
+        };
+
+        loop {
+            let event = self.queue.borrow_mut().pop_front();
+            match event {
+                Some(event) => listener(event),
+                None => break,
+            }
+        }
+    }
+}
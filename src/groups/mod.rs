@@ -0,0 +1,228 @@
+//! Named groups of containers, with rules for moving items between them
+//!
+//! Requires that feature `web-sys` be turned on.
+//!
+//! Each container registered with [`Groups`](Groups) belongs to a
+//! [`Group`](Group), which decides whether items may be taken out of its
+//! containers ([`Pull`](Pull)) and whether items may be dropped into them
+//! ([`Put`](Put)). From these rules, `Groups` derives the
+//! [`accepts`](crate::Options::accepts) and [`copy`](crate::Options::copy)
+//! options, so that they no longer have to be written by hand.
+//!
+//! Items can always be sorted within the container they come from. Moving
+//! them to another container requires both the `pull` rule of the source
+//! and the `put` rule of the target to allow it. Containers that were not
+//! registered only accept their own items.
+//!
+//! ```no_run
+//! use dragula::*;
+//! use dragula::groups::{Group, Groups, Pull, Put};
+//!
+//! let doc = web_sys::window().unwrap().document().unwrap();
+//! let backlog = doc.get_element_by_id("backlog").unwrap();
+//! let sprint = doc.get_element_by_id("sprint").unwrap();
+//! let templates = doc.get_element_by_id("templates").unwrap();
+//!
+//! let groups = Groups::new();
+//! // The backlog can give to the sprint, but the sprint cannot give back
+//! groups.add(&backlog, Group::new("backlog").put(Put::No));
+//! groups.add(
+//!     &sprint,
+//!     Group::new("sprint")
+//!         .pull(Pull::No)
+//!         .put(Put::From(vec![String::from("backlog")])),
+//! );
+//! // Templates are copied, and nothing can be dropped on them
+//! let palette = Group::new("templates").pull(Pull::Clone).put(Put::No);
+//! groups.add(&templates, palette);
+//!
+//! let options = groups.apply(Options::default());
+//! let drake = dragula_options(&groups.containers(), options);
+//!
+//! //--snip--
+//! ```
+use crate::options::{CopyValue, Options};
+use crate::typed;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::JsValue;
+use web_sys::Element;
+
+/// Whether items may be taken out of the containers of a group
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Pull {
+    /// Items may be moved to any group that will take them
+    Yes,
+    /// Items may not leave the container
+    No,
+    /// Items are copied rather than moved, to any group that will take them
+    Clone,
+    /// Items may only be moved to the groups with these names
+    To(Vec<String>),
+}
+
+/// Whether items may be dropped into the containers of a group
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Put {
+    /// Items from any group are accepted
+    Yes,
+    /// Items from other containers are never accepted
+    No,
+    /// Only items from the groups with these names are accepted
+    From(Vec<String>),
+}
+
+/// The name and rules of a group of containers
+///
+/// Created with [`Group::new`](Group::new), which allows items to be pulled
+/// into any group, and only accepts items from groups of the same name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    /// The name other groups refer to this group by
+    pub name: String,
+    /// Whether items may be taken out of the containers of this group
+    pub pull: Pull,
+    /// Whether items may be dropped into the containers of this group
+    pub put: Put,
+}
+
+impl Group {
+    /// Creates a group named `name`, with [`Pull::Yes`](Pull::Yes), and a
+    /// [`Put::From`](Put::From) listing only `name` itself
+    pub fn new(name: &str) -> Self {
+        Group {
+            name: String::from(name),
+            pull: Pull::Yes,
+            put: Put::From(vec![String::from(name)]),
+        }
+    }
+
+    /// Sets [`Group::pull`](Group::pull)
+    pub fn pull(mut self, pull: Pull) -> Self {
+        self.pull = pull;
+        self
+    }
+
+    /// Sets [`Group::put`](Group::put)
+    pub fn put(mut self, put: Put) -> Self {
+        self.put = put;
+        self
+    }
+
+    fn gives_to(&self, other: &Group) -> bool {
+        match &self.pull {
+            Pull::Yes | Pull::Clone => true,
+            Pull::No => false,
+            Pull::To(names) => names.contains(&other.name),
+        }
+    }
+
+    fn takes_from(&self, other: &Group) -> bool {
+        match &self.put {
+            Put::Yes => true,
+            Put::No => false,
+            Put::From(names) => names.contains(&other.name),
+        }
+    }
+}
+
+/// A registry of containers and the [`Group`](Group) each belongs to
+///
+/// See the [module documentation](self) for details. Clones share the same
+/// registry, and the closures returned by [`accepts`](Groups::accepts) and
+/// [`copy`](Groups::copy) always use its current contents, so containers can
+/// be added or removed after the `Drake` is created.
+#[derive(Clone, Default)]
+pub struct Groups {
+    containers: Rc<RefCell<Vec<(Element, Group)>>>,
+}
+
+impl Groups {
+    /// Creates an empty registry
+    pub fn new() -> Self {
+        Groups::default()
+    }
+
+    /// Puts `container` in `group`, replacing the group it was in before
+    pub fn add(&self, container: &Element, group: Group) {
+        let mut containers = self.containers.borrow_mut();
+        match containers.iter_mut().find(|(el, _)| el == container) {
+            Some((_, existing)) => *existing = group,
+            None => containers.push((container.clone(), group)),
+        }
+    }
+
+    /// Takes `container` out of its group
+    pub fn remove(&self, container: &Element) {
+        self.containers
+            .borrow_mut()
+            .retain(|(el, _)| el != container);
+    }
+
+    /// Returns the group `container` belongs to, if any
+    pub fn group_of(&self, container: &Element) -> Option<Group> {
+        self.containers
+            .borrow()
+            .iter()
+            .find(|(el, _)| el == container)
+            .map(|(_, group)| group.clone())
+    }
+
+    /// Returns every registered container, in the order they were added
+    pub fn containers(&self) -> Vec<Element> {
+        self.containers
+            .borrow()
+            .iter()
+            .map(|(el, _)| el.clone())
+            .collect()
+    }
+
+    /// Whether an item may be moved from `source` into `target`
+    pub fn allows(&self, source: &Element, target: &Element) -> bool {
+        if source == target {
+            return true;
+        }
+        match (self.group_of(source), self.group_of(target)) {
+            (Some(source), Some(target)) => {
+                source.gives_to(&target) && target.takes_from(&source)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns a closure for [`Options::accepts`](crate::Options::accepts)
+    /// that follows the rules of the groups
+    pub fn accepts(
+        &self,
+    ) -> Box<dyn FnMut(JsValue, JsValue, JsValue, JsValue) -> bool> {
+        let groups = self.clone();
+        typed::accepts(move |_el, target, source, _sibling| {
+            groups.allows(source, target)
+        })
+    }
+
+    /// Returns a [`CopyValue::Func`](crate::options::CopyValue::Func) for
+    /// [`Options::copy`](crate::Options::copy) that copies items out of
+    /// groups with [`Pull::Clone`](Pull::Clone)
+    pub fn copy(&self) -> CopyValue {
+        let groups = self.clone();
+        typed::copy(move |_el, source| {
+            groups
+                .group_of(source)
+                .is_some_and(|group| group.pull == Pull::Clone)
+        })
+    }
+
+    /// Sets the [`accepts`](crate::Options::accepts) and
+    /// [`copy`](crate::Options::copy) fields of `options` from the groups
+    pub fn apply(&self, options: Options) -> Options {
+        Options {
+            accepts: self.accepts(),
+            copy: self.copy(),
+            ..options
+        }
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,127 @@
+use super::*;
+use crate::test_utils::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| String::from(*name)).collect()
+}
+
+#[wasm_bindgen_test]
+fn same_group_names_share_items() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(3, 1);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let groups = Groups::new();
+        groups.add(&containers[0], Group::new("tasks"));
+        groups.add(&containers[1], Group::new("tasks"));
+        groups.add(&containers[2], Group::new("other"));
+
+        assert!(groups.allows(&containers[0], &containers[1]));
+        assert!(groups.allows(&containers[1], &containers[0]));
+        assert!(!groups.allows(&containers[0], &containers[2]));
+        assert!(groups.allows(&containers[2], &containers[2]));
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn one_way_transfer() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(3, 1);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let (backlog, sprint, done) =
+            (&containers[0], &containers[1], &containers[2]);
+        let groups = Groups::new();
+        groups.add(backlog, Group::new("backlog").put(Put::No));
+        groups.add(
+            sprint,
+            Group::new("sprint")
+                .pull(Pull::To(names(&["done"])))
+                .put(Put::From(names(&["backlog"]))),
+        );
+        groups.add(done, Group::new("done").put(Put::Yes));
+
+        assert!(groups.allows(backlog, sprint));
+        assert!(!groups.allows(sprint, backlog));
+        assert!(groups.allows(sprint, done));
+        assert!(groups.allows(backlog, done));
+        assert!(!groups.allows(done, sprint));
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn unregistered_containers_keep_their_items() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 1);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let groups = Groups::new();
+        groups.add(&containers[0], Group::new("tasks").put(Put::Yes));
+
+        assert!(!groups.allows(&containers[1], &containers[0]));
+        assert!(!groups.allows(&containers[0], &containers[1]));
+
+        groups.add(&containers[1], Group::new("tasks"));
+        assert!(groups.allows(&containers[0], &containers[1]));
+
+        groups.remove(&containers[1]);
+        assert_eq!(groups.group_of(&containers[1]), None);
+        assert_eq!(groups.containers(), vec![containers[0].clone()]);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn apply_derives_accepts_and_copy() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 1);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let (palette, lane) = (&containers[0], &containers[1]);
+        let groups = Groups::new();
+        groups.add(palette, Group::new("palette").pull(Pull::Clone));
+        groups.add(lane, Group::new("lane").put(Put::Yes));
+
+        let options = groups.apply(Options::default());
+        let item = palette.first_element_child().unwrap();
+
+        let mut copy = match options.copy {
+            CopyValue::Func(copy) => copy,
+            CopyValue::Bool(_) => panic!("apply should set a copy closure"),
+        };
+        assert!(copy(item.clone().into(), palette.into()));
+        assert!(!copy(item.clone().into(), lane.into()));
+
+        let mut accepts = options.accepts;
+        assert!(accepts(
+            item.clone().into(),
+            lane.into(),
+            palette.into(),
+            JsValue::NULL,
+        ));
+        assert!(!accepts(
+            item.into(),
+            palette.into(),
+            lane.into(),
+            JsValue::NULL
+        ));
+    };
+
+    run_dom_test(test, &html);
+}
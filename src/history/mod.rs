@@ -0,0 +1,337 @@
+//! Undo and redo for drag operations
+//!
+//! Requires that feature `web-sys` be turned on.
+//!
+//! A [`History`](History) records every completed `drop` and `remove` of a
+//! [`Drake`](crate::Drake) as an [`Operation`](Operation).
+//! [`undo`](History::undo) and [`redo`](History::redo) replay the inverse or
+//! the original move in the DOM, and emit the same events a real drag would,
+//! so that listeners and models bound through
+//! [`controlled`](crate::Drake::controlled) or
+//! [`sortable`](crate::sortable) stay in step:
+//!
+//! - Undoing a move emits `drag`, `drop` and `dragend`, as if the item had
+//!   been dragged back to where it came from.
+//! - Undoing a copy emits `drag`, `remove` and `dragend`, since the copy is
+//!   taken out of the page.
+//! - Undoing a `remove` emits `drag`, `drop` and `dragend`, with the item
+//!   dropped back into the container it was removed from.
+//! - Redoing emits the events of the original operation, including `cloned`
+//!   for copies.
+//!
+//! Only the most recent operations are kept, up to a
+//! [depth](History::with_depth) of [`DEFAULT_DEPTH`](DEFAULT_DEPTH) unless
+//! set otherwise.
+//!
+//! ```no_run
+//! use dragula::*;
+//! use dragula::history::History;
+//!
+//! let doc = web_sys::window().unwrap().document().unwrap();
+//! let element = doc.get_element_by_id("drag-container").unwrap();
+//!
+//! let mut drake = dragula(&[element]);
+//! let history = History::new(&mut drake);
+//!
+//! //--snip--
+//!
+//! // On Ctrl+Z
+//! history.undo();
+//! // On Ctrl+Shift+Z
+//! history.redo();
+//! ```
+use crate::drake::{insert_before, DragState};
+use crate::event::*;
+use crate::{Drake, ListenerHandle};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use wasm_bindgen::JsValue;
+use web_sys::Element;
+
+/// The number of operations kept by [`History::new`](History::new)
+pub const DEFAULT_DEPTH: usize = 100;
+
+/// A completed `drop` or `remove`, as recorded by a [`History`](History)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Operation {
+    /// The element that was dropped or removed. When `copy` is `true`, this
+    /// is the copy.
+    pub el: Element,
+    /// The element that was dragged, which is the same as `el` unless
+    /// `copy` is `true`
+    pub original: Element,
+    /// The container the item was lifted from
+    pub source: Element,
+    /// The element the item was placed before in `source`, or `None` if it
+    /// was the last one
+    pub source_sibling: Option<Element>,
+    /// The container the item was dropped in, or `None` if it was removed
+    pub target: Option<Element>,
+    /// The element the item was placed before in `target`, or `None` if it
+    /// was placed last
+    pub target_sibling: Option<Element>,
+    /// Whether the item was copied rather than moved
+    pub copy: bool,
+}
+
+fn nullable(el: Option<&Element>) -> JsValue {
+    el.map_or(JsValue::NULL, JsValue::from)
+}
+
+struct State {
+    drake: Drake,
+    depth: usize,
+    undo: VecDeque<Operation>,
+    redo: Vec<Operation>,
+    /// Follows the drag in progress, so that its operation can be recorded
+    drag: DragState,
+    /// Set while events are emitted by `undo` or `redo`, so that they are
+    /// not recorded
+    replaying: bool,
+}
+
+impl State {
+    /// Adds an operation that can be undone, forgetting the oldest one if
+    /// the history is full
+    fn push(&mut self, operation: Operation) {
+        if self.depth == 0 {
+            return;
+        }
+        if self.undo.len() == self.depth {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(operation);
+    }
+
+    fn record(&mut self, operation: Operation) {
+        self.redo.clear();
+        self.push(operation);
+    }
+
+    fn track(&mut self, event: DragulaEvent<Element>) {
+        if self.replaying {
+            return;
+        }
+
+        let finished = match &event {
+            DragulaEvent::Drop(DropEvent { el, target, .. }) => {
+                Some((el, Some(target)))
+            }
+            DragulaEvent::Remove(RemoveEvent { el, .. }) => Some((el, None)),
+            _ => None,
+        };
+        let operation = finished.zip(self.drag.origin.as_ref()).map(
+            |((el, target), origin)| Operation {
+                el: el.clone(),
+                original: origin.item.clone(),
+                source: origin.container.clone(),
+                source_sibling: origin.sibling.clone(),
+                target: target.cloned(),
+                // The sibling of the event is stale for drops that Dragula
+                // did not place, like keyboard moves
+                target_sibling: target.and(el.next_element_sibling()),
+                copy: target.is_some() && self.drag.copying(),
+            },
+        );
+
+        self.drag.track(&event);
+        if let Some(operation) = operation {
+            self.record(operation);
+        }
+    }
+}
+
+/// Emits `drag` for `dragged`, then moves `el` to `to`, or out of the page,
+/// and emits the events that report it, like a real drag would
+fn replay_move(
+    drake: &Drake,
+    dragged: &Element,
+    el: &Element,
+    from: &Element,
+    to: Option<(&Element, Option<&Element>)>,
+) {
+    let el_value = JsValue::from(el);
+    let from_value = JsValue::from(from);
+
+    drake.emit_2("drag", &JsValue::from(dragged), &from_value);
+    match to {
+        Some((target, sibling)) => {
+            insert_before(target, el, sibling);
+            drake.emit_4(
+                "drop",
+                &el_value,
+                &JsValue::from(target),
+                &from_value,
+                &nullable(sibling),
+            );
+        }
+        None => {
+            el.remove();
+            drake.emit_3("remove", &el_value, &from_value, &from_value);
+        }
+    }
+    drake.emit_1("dragend", &el_value);
+}
+
+fn undo(drake: &Drake, operation: &Operation) {
+    let Operation {
+        el,
+        source,
+        source_sibling,
+        target,
+        copy,
+        ..
+    } = operation;
+
+    let back = Some((source, source_sibling.as_ref()));
+    match target {
+        Some(target) if *copy => replay_move(drake, el, el, target, None),
+        Some(target) => replay_move(drake, el, el, target, back),
+        None => replay_move(drake, el, el, source, back),
+    }
+}
+
+fn redo(drake: &Drake, operation: &Operation) {
+    let Operation {
+        el,
+        original,
+        source,
+        target,
+        target_sibling,
+        copy,
+        ..
+    } = operation;
+
+    if *copy {
+        drake.emit_3(
+            "cloned",
+            &JsValue::from(el),
+            &JsValue::from(original),
+            &JsValue::from("copy"),
+        );
+    }
+    let to = target
+        .as_ref()
+        .map(|target| (target, target_sibling.as_ref()));
+    replay_move(drake, original, el, source, to);
+}
+
+/// Records the operations of a [`Drake`](crate::Drake), so that they can be
+/// undone and redone
+///
+/// See the [module documentation](self) for details. Operations stop being
+/// recorded once the `History` is dropped.
+pub struct History {
+    state: Rc<RefCell<State>>,
+    _listener: ListenerHandle,
+}
+
+impl History {
+    /// Starts recording the operations of `drake`, keeping up to
+    /// [`DEFAULT_DEPTH`](DEFAULT_DEPTH) of them
+    pub fn new(drake: &mut Drake) -> Self {
+        History::with_depth(drake, DEFAULT_DEPTH)
+    }
+
+    /// Starts recording the operations of `drake`, keeping up to `depth` of
+    /// them. Older operations are forgotten as new ones are recorded.
+    pub fn with_depth(drake: &mut Drake, depth: usize) -> Self {
+        let state = Rc::new(RefCell::new(State {
+            drake: drake.clone(),
+            depth,
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            drag: DragState::default(),
+            replaying: false,
+        }));
+
+        let s = state.clone();
+        let listener =
+            drake.on_element_event(move |event| s.borrow_mut().track(event));
+
+        History {
+            state,
+            _listener: listener,
+        }
+    }
+
+    /// Reverts the most recent operation, returning `false` if there is
+    /// none, or if an item is being dragged
+    pub fn undo(&self) -> bool {
+        let operation = {
+            let mut state = self.state.borrow_mut();
+            if state.drake.dragging() {
+                return false;
+            }
+            match state.undo.pop_back() {
+                Some(operation) => operation,
+                None => return false,
+            }
+        };
+
+        self.emitting(|drake| undo(drake, &operation));
+        self.state.borrow_mut().redo.push(operation);
+        true
+    }
+
+    /// Applies the most recently undone operation again, returning `false`
+    /// if there is none, or if an item is being dragged. Undone operations
+    /// can no longer be redone once a new one is recorded.
+    pub fn redo(&self) -> bool {
+        let operation = {
+            let mut state = self.state.borrow_mut();
+            if state.drake.dragging() {
+                return false;
+            }
+            match state.redo.pop() {
+                Some(operation) => operation,
+                None => return false,
+            }
+        };
+
+        self.emitting(|drake| redo(drake, &operation));
+        self.state.borrow_mut().push(operation);
+        true
+    }
+
+    /// Runs `emit` without recording the events it emits, or holding the
+    /// state, since listeners may use the `History`
+    fn emitting<F>(&self, emit: F)
+    where
+        F: FnOnce(&Drake),
+    {
+        let drake = {
+            let mut state = self.state.borrow_mut();
+            state.replaying = true;
+            state.drake.clone()
+        };
+        emit(&drake);
+        self.state.borrow_mut().replaying = false;
+    }
+
+    /// Whether there is an operation to [`undo`](History::undo)
+    pub fn can_undo(&self) -> bool {
+        !self.state.borrow().undo.is_empty()
+    }
+
+    /// Whether there is an operation to [`redo`](History::redo)
+    pub fn can_redo(&self) -> bool {
+        !self.state.borrow().redo.is_empty()
+    }
+
+    /// Returns the operations that can be undone, oldest first
+    pub fn operations(&self) -> Vec<Operation> {
+        self.state.borrow().undo.iter().cloned().collect()
+    }
+
+    /// Forgets every recorded operation
+    pub fn clear(&self) {
+        let mut state = self.state.borrow_mut();
+        state.undo.clear();
+        state.redo.clear();
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,281 @@
+use super::*;
+use crate::options::CopyValue;
+use crate::sortable::{Model, SortableList};
+use crate::test_utils::*;
+use crate::{dragula, dragula_options, Options};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Drags `el` into `target` before `sibling`, then drops it
+fn drag_to(
+    drake: &mut Drake,
+    el: &Element,
+    target: &Element,
+    sibling: Option<&Element>,
+) {
+    drake.start(el);
+    target
+        .insert_before(el, sibling.map(|s| s.as_ref()))
+        .unwrap();
+    drake.end();
+}
+
+/// Drags `el` out of the page
+fn remove(drake: &mut Drake, el: &Element) {
+    drake.start(el);
+    drake.remove();
+}
+
+#[wasm_bindgen_test]
+fn undo_and_redo_move() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 2);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let mut drake = dragula(&containers);
+        let history = History::new(&mut drake);
+
+        let item = containers[0].first_element_child().unwrap();
+        drag_to(&mut drake, &item, &containers[1], None);
+        assert_eq!(ids(&containers[1]), ["drag_1_0", "drag_1_1", "drag_0_0"]);
+        assert!(history.can_undo());
+
+        let operation = &history.operations()[0];
+        assert_eq!(operation.source, containers[0]);
+        assert_eq!(operation.target.as_ref(), Some(&containers[1]));
+        assert_eq!(
+            operation
+                .source_sibling
+                .as_ref()
+                .map(Element::id)
+                .as_deref(),
+            Some("drag_0_1")
+        );
+        assert!(!operation.copy);
+
+        assert!(history.undo());
+        assert_eq!(ids(&containers[0]), ["drag_0_0", "drag_0_1"]);
+        assert_eq!(ids(&containers[1]), ["drag_1_0", "drag_1_1"]);
+        assert!(!history.can_undo());
+        assert!(history.can_redo());
+
+        assert!(history.redo());
+        assert_eq!(ids(&containers[1]), ["drag_1_0", "drag_1_1", "drag_0_0"]);
+        assert!(!history.redo());
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn replay_emits_events_without_recording_them() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 1);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let mut drake = dragula(&containers);
+        let history = History::new(&mut drake);
+
+        let drops = Rc::new(RefCell::new(Vec::new()));
+        let drops_ref = drops.clone();
+        let _listener = drake.on_element_event(move |event| {
+            if let DragulaEvent::Drop(drop) = event {
+                drops_ref.borrow_mut().push(drop);
+            }
+        });
+
+        let item = containers[0].first_element_child().unwrap();
+        drag_to(&mut drake, &item, &containers[1], None);
+        history.undo();
+
+        let drops = drops.borrow();
+        assert_eq!(drops.len(), 2);
+        assert_eq!(drops[1].el, item);
+        assert_eq!(drops[1].target, containers[0]);
+        assert_eq!(drops[1].source, containers[1]);
+        assert_eq!(history.operations().len(), 0);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn undo_remove_restores_item() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(1, 3);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let mut drake = dragula(&containers);
+        let history = History::new(&mut drake);
+
+        let item = containers[0].children().item(1).unwrap();
+        remove(&mut drake, &item);
+        assert_eq!(ids(&containers[0]), ["drag_0_0", "drag_0_2"]);
+
+        assert!(history.undo());
+        assert_eq!(ids(&containers[0]), ["drag_0_0", "drag_0_1", "drag_0_2"]);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn depth_limits_operations_and_new_operation_clears_redo() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 3);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let mut drake = dragula(&containers);
+        let history = History::with_depth(&mut drake, 2);
+
+        for _ in 0..3 {
+            let item = containers[0].first_element_child().unwrap();
+            drag_to(&mut drake, &item, &containers[1], None);
+        }
+        assert_eq!(history.operations().len(), 2);
+
+        assert!(history.undo());
+        assert!(history.can_redo());
+
+        let item = containers[1].first_element_child().unwrap();
+        drag_to(&mut drake, &item, &containers[0], None);
+        assert!(!history.can_redo());
+
+        history.clear();
+        assert!(!history.can_undo());
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn undo_and_redo_copy() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 2);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let mut drake = dragula_options(
+            &containers,
+            Options {
+                copy: CopyValue::Bool(true),
+                ..Options::default()
+            },
+        );
+        let history = History::new(&mut drake);
+
+        let copies = Rc::new(RefCell::new(Vec::new()));
+        let copies_ref = copies.clone();
+        let _listener = drake.on_cloned(move |copy, _original, _kind| {
+            copies_ref.borrow_mut().push(Element::from(copy));
+        });
+
+        let item = containers[0].first_element_child().unwrap();
+        drake.start(&item);
+        let copy = copies.borrow()[0].clone();
+        containers[1].append_child(&copy).unwrap();
+        drake.end();
+
+        let operation = &history.operations()[0];
+        assert!(operation.copy);
+        assert_eq!(operation.el, copy);
+        assert_eq!(operation.original, item);
+
+        assert!(history.undo());
+        assert_eq!(ids(&containers[0]), ["drag_0_0", "drag_0_1"]);
+        assert_eq!(ids(&containers[1]), ["drag_1_0", "drag_1_1"]);
+        assert_eq!(copy.parent_element(), None);
+
+        assert!(history.redo());
+        assert_eq!(ids(&containers[0]), ["drag_0_0", "drag_0_1"]);
+        assert_eq!(containers[1].last_element_child(), Some(copy));
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn undo_and_redo_keep_sortable_models_in_step() {
+    console_error_panic_hook::set_once();
+
+    let html = keyed_containers(2, 2);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let model = |keys: &[&str]| {
+            let keys = keys.iter().map(|key| String::from(*key)).collect();
+            Rc::new(RefCell::new(keys))
+        };
+        let todo: Model<String> = model(&["0_0", "0_1"]);
+        let done: Model<String> = model(&["1_0", "1_1"]);
+        let mut list = SortableList::builder("data-id", String::clone)
+            .bind(&containers[0], todo.clone())
+            .bind(&containers[1], done.clone())
+            .build(Options::default());
+        let history = History::new(list.drake_mut());
+
+        let item = containers[0].first_element_child().unwrap();
+        let sibling = containers[1].last_element_child().unwrap();
+        drag_to(list.drake_mut(), &item, &containers[1], Some(&sibling));
+        assert_eq!(*todo.borrow(), ["0_1"]);
+        assert_eq!(*done.borrow(), ["1_0", "0_0", "1_1"]);
+
+        assert!(history.undo());
+        assert_eq!(*todo.borrow(), ["0_0", "0_1"]);
+        assert_eq!(*done.borrow(), ["1_0", "1_1"]);
+        assert!(history.redo());
+        assert_eq!(*done.borrow(), ["1_0", "0_0", "1_1"]);
+
+        let item = containers[1].first_element_child().unwrap();
+        remove(list.drake_mut(), &item);
+        assert_eq!(*done.borrow(), ["0_0", "1_1"]);
+
+        assert!(history.undo());
+        assert_eq!(*done.borrow(), ["1_0", "0_0", "1_1"]);
+        assert!(history.redo());
+        assert_eq!(*done.borrow(), ["0_0", "1_1"]);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn sortable_keeps_removed_entries_up_to_its_depth() {
+    console_error_panic_hook::set_once();
+
+    let html = keyed_containers(1, 3);
+
+    let test = |element: &Element| {
+        let container = element.first_element_child().unwrap();
+        let keys = ["0_0", "0_1", "0_2"].iter().map(|key| String::from(*key));
+        let model: Model<String> = Rc::new(RefCell::new(keys.collect()));
+        let mut list = SortableList::builder("data-id", String::clone)
+            .bind(&container, model.clone())
+            .removed_depth(1)
+            .build(Options::default());
+        let history = History::new(list.drake_mut());
+
+        let item = container.first_element_child().unwrap();
+        remove(list.drake_mut(), &item);
+        let item = container.first_element_child().unwrap();
+        remove(list.drake_mut(), &item);
+        assert_eq!(*model.borrow(), ["0_2"]);
+
+        assert!(history.undo());
+        assert_eq!(*model.borrow(), ["0_1", "0_2"]);
+        assert!(history.undo());
+        assert_eq!(*model.borrow(), ["0_1", "0_2"]);
+    };
+
+    run_dom_test(test, &html);
+}
@@ -0,0 +1,172 @@
+//! A [Dioxus](https://dioxuslabs.com) hook for using Dragula
+//!
+//! Requires that feature `dioxus` be turned on.
+//!
+//! [`use_dragula`](use_dragula) creates a [`Drake`](crate::Drake) for the
+//! component, and destroys it when the component's scope is dropped.
+//! Containers are registered as they are mounted, by passing their
+//! `onmounted` events to [`UseDragula::mount`](UseDragula::mount), so no
+//! lookups by id are needed. Dioxus has no event for elements that are
+//! unmounted, so containers taken out of the page are only removed from the
+//! `Drake` the next time a container is mounted. The `drop`, `cancel`, and `remove` events reach
+//! the component as [`EventHandler`]s, with the elements involved typed as
+//! [`Element`](web_sys::Element).
+//!
+//! ```no_run
+//! use dragula::*;
+//! use dragula::integration::dioxus::{use_dragula, DragulaHandlers};
+//! use dioxus::prelude::*;
+//!
+//! #[component]
+//! fn Tasks() -> Element {
+//!     let dragula = use_dragula(Options::default, DragulaHandlers {
+//!         on_drop: Some(EventHandler::new(|drop: event::DropEvent<_>| {
+//!             // `drop.el` was dropped into `drop.target`
+//!         })),
+//!         ..DragulaHandlers::default()
+//!     });
+//!
+//!     rsx! {
+//!         ul {
+//!             onmounted: move |event| dragula.mount(event),
+//!             li { "Write" }
+//!             li { "Review" }
+//!             li { "Ship" }
+//!         }
+//!     }
+//! }
+//! ```
+use crate::event::*;
+use crate::{dragula_owned, Drake, Options, OwnedDrake};
+use dioxus::html::MountedEvent;
+use dioxus::prelude::{use_drop, use_hook, EventHandler};
+use dioxus::signals::{CopyValue, ReadableExt, WritableExt};
+use dioxus::web::WebEventExt;
+
+/// Handlers for the events of the [`Drake`](crate::Drake) created by
+/// [`use_dragula`](use_dragula)
+///
+/// Handlers that are `None` are skipped.
+#[derive(Clone, Copy, Default)]
+pub struct DragulaHandlers {
+    /// Called when an item is dropped into a container.
+    /// See [`Drake::on_drop`](crate::Drake::on_drop).
+    pub on_drop: Option<EventHandler<DropEvent<web_sys::Element>>>,
+    /// Called when a drag ends without a change.
+    /// See [`Drake::on_cancel`](crate::Drake::on_cancel).
+    pub on_cancel: Option<EventHandler<CancelEvent<web_sys::Element>>>,
+    /// Called when an item is removed from the DOM.
+    /// See [`Drake::on_remove`](crate::Drake::on_remove).
+    pub on_remove: Option<EventHandler<RemoveEvent<web_sys::Element>>>,
+}
+
+struct DragulaState {
+    drake: Option<OwnedDrake>,
+    handlers: DragulaHandlers,
+}
+
+/// The [`Drake`](crate::Drake) created by [`use_dragula`](use_dragula)
+///
+/// This is `Copy`, so it can be moved into any number of event handlers.
+#[derive(Clone, Copy)]
+pub struct UseDragula {
+    state: CopyValue<DragulaState>,
+}
+
+impl UseDragula {
+    /// Adds the element that was mounted to the containers of the `Drake`.
+    /// Meant to be used as the `onmounted` handler of each container.
+    ///
+    /// Containers that are no longer in the page, because they were
+    /// unmounted, are removed at the same time. Does nothing outside of the
+    /// web renderer.
+    pub fn mount(&self, event: MountedEvent) {
+        let mut state = self.state;
+        if let Some(container) = event.try_as_web_event() {
+            if let Some(drake) = state.write().drake.as_mut() {
+                let mut containers = drake.container_elements();
+                containers.retain(|container| container.is_connected());
+                containers.push(container);
+                drake.set_containers(&containers);
+            }
+        }
+    }
+
+    /// Returns the `Drake`, or `None` once the scope has been dropped
+    pub fn drake(&self) -> Option<Drake> {
+        let state = self.state.try_read().ok()?;
+        state.drake.as_ref().map(|drake| Drake::clone(drake))
+    }
+}
+
+fn dispatch(
+    state: CopyValue<DragulaState>,
+    event: DragulaEvent<web_sys::Element>,
+) {
+    // Copied out so that no borrow is held while a handler runs
+    let handlers = match state.try_read() {
+        Ok(state) => state.handlers,
+        Err(_) => return,
+    };
+
+    match event {
+        DragulaEvent::Drop(drop) => {
+            if let Some(on_drop) = handlers.on_drop {
+                on_drop.call(drop);
+            }
+        }
+        DragulaEvent::Cancel(cancel) => {
+            if let Some(on_cancel) = handlers.on_cancel {
+                on_cancel.call(cancel);
+            }
+        }
+        DragulaEvent::Remove(remove) => {
+            if let Some(on_remove) = handlers.on_remove {
+                on_remove.call(remove);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Creates a `Drake` for the current component, without any containers.
+/// Containers are added with [`UseDragula::mount`](UseDragula::mount).
+///
+/// `options` is only called on the first render. `handlers` may change from
+/// one render to the next, and the latest ones are always used. The `Drake`
+/// is destroyed, and its closures freed, when the scope is dropped.
+pub fn use_dragula<F>(options: F, handlers: DragulaHandlers) -> UseDragula
+where
+    F: FnOnce() -> Options,
+{
+    let dragula = use_hook(|| {
+        let containers: [web_sys::Element; 0] = [];
+        let mut drake = dragula_owned(&containers, options());
+
+        let mut state = CopyValue::new(DragulaState {
+            drake: None,
+            handlers,
+        });
+
+        let listener =
+            drake.on_element_event(move |event| dispatch(state, event));
+        drake.keep(listener);
+        state.write().drake = Some(drake);
+
+        UseDragula { state }
+    });
+
+    let mut state = dragula.state;
+    state.write().handlers = handlers;
+
+    use_drop(move || {
+        if let Ok(mut state) = state.try_write() {
+            state.drake.take();
+        }
+    });
+
+    dragula
+}
+
+#[cfg(test)]
+mod test;
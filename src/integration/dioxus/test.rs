@@ -0,0 +1,138 @@
+use super::*;
+use crate::test_utils::*;
+use dioxus::dioxus_core::{VNode, VirtualDom};
+use dioxus::web::launch::launch_virtual_dom;
+use dioxus::web::Config;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen_test::*;
+use web_sys::Element;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+type Drops = Rc<RefCell<Vec<DropEvent<Element>>>>;
+
+#[derive(Clone)]
+struct ProbeProps {
+    dragula: Rc<RefCell<Option<UseDragula>>>,
+    drops: Drops,
+}
+
+fn probe(props: ProbeProps) -> dioxus::prelude::Element {
+    let drops = props.drops.clone();
+    let handlers = DragulaHandlers {
+        on_drop: Some(EventHandler::new(move |drop| {
+            drops.borrow_mut().push(drop);
+        })),
+        ..DragulaHandlers::default()
+    };
+
+    let dragula = use_dragula(Options::default, handlers);
+    *props.dragula.borrow_mut() = Some(dragula);
+
+    VNode::empty()
+}
+
+fn probe_dom() -> (VirtualDom, UseDragula, Drops) {
+    let props = ProbeProps {
+        dragula: Rc::new(RefCell::new(None)),
+        drops: Rc::new(RefCell::new(Vec::new())),
+    };
+
+    let mut dom = VirtualDom::new_with_props(probe, props.clone());
+    dom.rebuild_in_place();
+
+    let dragula = props.dragula.borrow().expect("Hook did not run");
+    (dom, dragula, props.drops)
+}
+
+#[wasm_bindgen_test]
+fn drop_calls_handler() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 2);
+
+    let test = |element: &Element| {
+        let (dom, dragula, drops) = probe_dom();
+        let mut drake = dragula.drake().expect("Drake was not created");
+
+        let source = element.first_element_child().unwrap();
+        let target = element.last_element_child().unwrap();
+        drake.set_containers(&[source.clone(), target.clone()]);
+
+        let item = source.first_element_child().unwrap();
+        dom.in_runtime(|| {
+            drake.start(&item);
+            target.append_child(&item).unwrap();
+            drake.end();
+        });
+
+        let drops = drops.borrow();
+        assert_eq!(drops.len(), 1);
+        assert_eq!(drops[0].el, item);
+        assert_eq!(drops[0].target, target);
+        assert_eq!(drops[0].source, source);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[derive(Clone)]
+struct ListProps {
+    dragula: Rc<RefCell<Option<UseDragula>>>,
+}
+
+fn list(props: ListProps) -> dioxus::prelude::Element {
+    use dioxus::prelude::*;
+
+    let dragula = use_dragula(Options::default, DragulaHandlers::default());
+    *props.dragula.borrow_mut() = Some(dragula);
+
+    rsx! {
+        div {
+            onmounted: move |event| dragula.mount(event),
+            div { "Item" }
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+async fn mount_adds_container() {
+    console_error_panic_hook::set_once();
+
+    let doc = web_sys::window().unwrap().document().unwrap();
+    let root = doc.create_element("div").unwrap();
+    doc.body().unwrap().append_child(&root).unwrap();
+
+    let props = ListProps {
+        dragula: Rc::new(RefCell::new(None)),
+    };
+    let dom = VirtualDom::new_with_props(list, props.clone());
+    launch_virtual_dom(dom, Config::new().rootelement(root.clone()));
+    frames(2).await;
+
+    let dragula = props.dragula.borrow().expect("Hook did not run");
+    let drake = dragula.drake().expect("Drake was not created");
+    let container = root.first_element_child().expect("List was not rendered");
+    assert_eq!(drake.container_elements(), [container]);
+
+    root.remove();
+}
+
+#[wasm_bindgen_test]
+fn dropping_scope_destroys_drake() {
+    console_error_panic_hook::set_once();
+
+    let (dom, dragula, _drops) = probe_dom();
+    let mut drake = dragula.drake().expect("Drake was not created");
+
+    let destroyed = Rc::new(RefCell::new(false));
+    let destroyed_ref = destroyed.clone();
+    let _listener = drake.on_destroy(move || {
+        *destroyed_ref.borrow_mut() = true;
+    });
+
+    drop(dom);
+
+    assert!(*destroyed.borrow());
+}
@@ -0,0 +1,192 @@
+//! Binds container order to [Leptos](https://leptos.dev) signals
+//!
+//! Requires that feature `leptos` be turned on.
+//!
+//! Each container is bound to an `RwSignal<Vec<K>>` holding the keys of the
+//! items it renders, in order. The [`Drake`](crate::Drake) runs in
+//! [controlled mode](crate::Drake::controlled): a drop is reverted, and the
+//! signals are updated instead, so that Leptos renders the new order. The
+//! `Drake` is destroyed through `on_cleanup` when the owner it was created
+//! under is cleaned up.
+//!
+//! Items must be rendered in the same order as their keys, with no other
+//! elements in the container, since moves are applied by index.
+//!
+//! A single container can be made sortable with the
+//! [`sortable`](sortable) directive:
+//!
+//! ```no_run
+//! use dragula::integration::leptos::sortable;
+//! use leptos::prelude::*;
+//!
+//! #[component]
+//! fn Tasks() -> impl IntoView {
+//!     let tasks = RwSignal::new(vec!["Write", "Review", "Ship"]);
+//!
+//!     view! {
+//!         <ul use:sortable=tasks>
+//!             <For each=move || tasks.get() key=|task| *task let:task>
+//!                 <li>{task}</li>
+//!             </For>
+//!         </ul>
+//!     }
+//! }
+//! ```
+//!
+//! Items can be dragged between several containers with
+//! [`use_dragula`](use_dragula):
+//!
+//! ```no_run
+//! use dragula::*;
+//! use dragula::integration::leptos::use_dragula;
+//! use leptos::html::Ul;
+//! use leptos::prelude::*;
+//!
+//! #[component]
+//! fn Board() -> impl IntoView {
+//!     let todo = RwSignal::new(vec!["Write", "Review"]);
+//!     let done = RwSignal::new(vec!["Ship"]);
+//!     let todo_ref = NodeRef::<Ul>::new();
+//!     let done_ref = NodeRef::<Ul>::new();
+//!
+//!     use_dragula(vec![(todo_ref, todo), (done_ref, done)], Options::default());
+//!
+//!     view! {
+//!         <ul node_ref=todo_ref>
+//!             <For each=move || todo.get() key=|task| *task let:task>
+//!                 <li>{task}</li>
+//!             </For>
+//!         </ul>
+//!         <ul node_ref=done_ref>
+//!             <For each=move || done.get() key=|task| *task let:task>
+//!                 <li>{task}</li>
+//!             </For>
+//!         </ul>
+//!     }
+//! }
+//! ```
+use crate::{dragula_owned, Drake, MoveIntent, Options};
+use leptos::html::ElementType;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+
+/// Moves the key described by `intent` between the bound signals
+fn apply<K>(bindings: &[(Element, RwSignal<Vec<K>>)], intent: MoveIntent)
+where
+    K: Clone + Send + Sync + 'static,
+{
+    let signal_of = |container: &Element| {
+        bindings
+            .iter()
+            .find(|(el, _)| el == container)
+            .map(|(_, keys)| *keys)
+    };
+
+    let from = match signal_of(&intent.from_container) {
+        Some(from) => from,
+        None => return,
+    };
+
+    let key = if intent.copy {
+        from.with_untracked(|keys| keys.get(intent.from_index).cloned())
+    } else {
+        from.try_update(|keys| {
+            (intent.from_index < keys.len())
+                .then(|| keys.remove(intent.from_index))
+        })
+        .flatten()
+    };
+
+    let to = intent.to_container.as_ref().and_then(signal_of);
+    if let (Some(key), Some(to), Some(to_index)) = (key, to, intent.to_index) {
+        to.update(|keys| keys.insert(to_index.min(keys.len()), key));
+    }
+}
+
+/// Activates Dragula on `containers`, keeping the order of each one in its
+/// signal, and destroys it when the current owner is cleaned up
+fn bind<K>(
+    bindings: Vec<(Element, RwSignal<Vec<K>>)>,
+    options: Options,
+) -> Drake
+where
+    K: Clone + Send + Sync + 'static,
+{
+    let containers: Vec<_> =
+        bindings.iter().map(|(el, _)| el.clone()).collect();
+
+    let mut drake = dragula_owned(&containers, options);
+    let listener = drake.controlled(move |intent| apply(&bindings, intent));
+    drake.keep(listener);
+    let handle = Drake::clone(&drake);
+
+    let drake = StoredValue::new_local(Some(drake));
+    on_cleanup(move || {
+        drake.try_update_value(|drake| drake.take());
+    });
+
+    handle
+}
+
+/// Activates Dragula on the containers behind the `NodeRef`s once they are
+/// all mounted, and binds each container to the signal holding the keys of
+/// its items.
+///
+/// Dropping an item moves its key between the signals, rather than moving
+/// the element. Copies, as configured through
+/// [`Options::copy`](crate::Options::copy), clone the key into the target
+/// signal. Removed items have their key removed.
+///
+/// `options` is used once, when the `Drake` is created. The `Drake` is
+/// destroyed when the current owner, usually the calling component, is
+/// cleaned up.
+pub fn use_dragula<E, K>(
+    containers: Vec<(NodeRef<E>, RwSignal<Vec<K>>)>,
+    options: Options,
+) where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+    K: Clone + Send + Sync + 'static,
+{
+    let owner = Owner::current();
+    let mut options = Some(options);
+
+    Effect::new(move |_| {
+        let bindings: Option<Vec<_>> = containers
+            .iter()
+            .map(|(node_ref, keys)| {
+                let el: Element = node_ref.get()?.unchecked_into();
+                Some((el, *keys))
+            })
+            .collect();
+
+        // Only bind once, under the caller's owner rather than the effect's
+        if let Some(bindings) = bindings {
+            if let Some(options) = options.take() {
+                let bind = move || {
+                    untrack(move || bind(bindings, options));
+                };
+                match &owner {
+                    Some(owner) => owner.with(bind),
+                    None => bind(),
+                }
+            }
+        }
+    });
+}
+
+/// Directive that makes the items of a single container sortable, keeping
+/// their order in `keys`
+///
+/// Used as `use:sortable=keys`. See [`use_dragula`](use_dragula) for the
+/// details, and for dragging between several containers.
+pub fn sortable<K>(el: Element, keys: RwSignal<Vec<K>>)
+where
+    K: Clone + Send + Sync + 'static,
+{
+    bind(vec![(el, keys)], Options::default());
+}
+
+#[cfg(test)]
+mod test;
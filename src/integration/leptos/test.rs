@@ -0,0 +1,150 @@
+use super::*;
+use crate::options::CopyValue;
+use crate::test_utils::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Drags `item` to the end of `target`, then drops it
+fn drag_into(drake: &mut Drake, item: &Element, target: &Element) {
+    drake.start(item);
+    target.append_child(item).unwrap();
+    drake.end();
+}
+
+#[wasm_bindgen_test]
+fn drop_moves_key_between_signals() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 2);
+
+    let test = |element: &Element| {
+        let owner = Owner::new();
+        let containers = containers(element);
+
+        owner.with(|| {
+            let todo = RwSignal::new(vec!["0_0", "0_1"]);
+            let done = RwSignal::new(vec!["1_0", "1_1"]);
+            let bindings = vec![
+                (containers[0].clone(), todo),
+                (containers[1].clone(), done),
+            ];
+            let mut drake = bind(bindings, Options::default());
+
+            let item = containers[0].first_element_child().unwrap();
+            drag_into(&mut drake, &item, &containers[1]);
+
+            // The DOM is left for Leptos to update
+            assert_eq!(containers[0].first_element_child(), Some(item));
+            assert_eq!(todo.get_untracked(), vec!["0_1"]);
+            assert_eq!(done.get_untracked(), vec!["1_0", "1_1", "0_0"]);
+        });
+
+        owner.cleanup();
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn drop_reorders_signal() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(1, 3);
+
+    let test = |element: &Element| {
+        let owner = Owner::new();
+        let container = containers(element).remove(0);
+
+        owner.with(|| {
+            let keys = RwSignal::new(vec![0, 1, 2]);
+            let bindings = vec![(container.clone(), keys)];
+            let mut drake = bind(bindings, Options::default());
+
+            let item = container.first_element_child().unwrap();
+            drag_into(&mut drake, &item, &container);
+
+            assert_eq!(keys.get_untracked(), vec![1, 2, 0]);
+        });
+
+        owner.cleanup();
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn copy_clones_key() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 1);
+
+    let test = |element: &Element| {
+        let owner = Owner::new();
+        let containers = containers(element);
+
+        owner.with(|| {
+            let palette = RwSignal::new(vec!["0_0"]);
+            let canvas = RwSignal::new(vec!["1_0"]);
+            let bindings = vec![
+                (containers[0].clone(), palette),
+                (containers[1].clone(), canvas),
+            ];
+            let options = Options {
+                copy: CopyValue::Bool(true),
+                ..Options::default()
+            };
+            let mut drake = bind(bindings, options);
+
+            let copies = Rc::new(RefCell::new(Vec::new()));
+            let copies_ref = copies.clone();
+            let _cloned = drake.on_cloned(move |clone, _original, _kind| {
+                copies_ref.borrow_mut().push(Element::from(clone));
+            });
+
+            let item = containers[0].first_element_child().unwrap();
+            drake.start(&item);
+            let copy = copies.borrow().first().unwrap().clone();
+            containers[1].append_child(&copy).unwrap();
+            drake.end();
+
+            assert_eq!(palette.get_untracked(), vec!["0_0"]);
+            assert_eq!(canvas.get_untracked(), vec!["1_0", "0_0"]);
+        });
+
+        owner.cleanup();
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn cleanup_destroys_drake() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(1, 1);
+
+    let test = |element: &Element| {
+        let owner = Owner::new();
+        let container = containers(element).remove(0);
+
+        let mut drake = owner.with(|| {
+            let keys = RwSignal::new(vec![0]);
+            bind(vec![(container.clone(), keys)], Options::default())
+        });
+
+        let destroyed = Rc::new(RefCell::new(false));
+        let destroyed_ref = destroyed.clone();
+        let _listener = drake.on_destroy(move || {
+            *destroyed_ref.borrow_mut() = true;
+        });
+
+        owner.cleanup();
+
+        assert!(*destroyed.borrow());
+    };
+
+    run_dom_test(test, &html);
+}
@@ -0,0 +1,13 @@
+//! Integrations with frontend frameworks
+//!
+//! Each framework has its own submodule, turned on by the feature of the same
+//! name.
+
+#[cfg(feature = "dioxus")]
+pub mod dioxus;
+#[cfg(feature = "leptos")]
+pub mod leptos;
+#[cfg(feature = "sycamore")]
+pub mod sycamore;
+#[cfg(feature = "yew")]
+pub mod yew;
@@ -0,0 +1,175 @@
+//! Binds container order to [Sycamore](https://sycamore.dev) signals
+//!
+//! Requires that feature `sycamore` be turned on.
+//!
+//! [`use_dragula`](use_dragula) attaches a [`Drake`](crate::Drake) to the
+//! containers behind a set of `NodeRef`s once they are mounted, and binds each
+//! one to a `Signal<Vec<T>>`. Every item element carries a key in one of its
+//! attributes, such as `data-id`, which identifies the entry it renders. The
+//! `Drake` runs in [controlled mode](crate::Drake::controlled): when an item
+//! is dropped or removed, the change Dragula made to the DOM is undone, and
+//! the entry is moved between the signals instead, leaving Sycamore to render
+//! the new order. The `Drake` is returned through a signal once the
+//! containers are mounted, and destroyed when the reactive scope it was
+//! created in is disposed.
+//!
+//! Items must be rendered in the same order as their entries, with no other
+//! elements in the container, since drops are placed by index.
+//!
+//! ```no_run
+//! use dragula::*;
+//! use dragula::integration::sycamore::use_dragula;
+//! use sycamore::prelude::*;
+//!
+//! #[component]
+//! fn Tasks() -> View {
+//!     let tasks = create_signal(vec![1, 2, 3]);
+//!     let container = create_node_ref();
+//!
+//!     use_dragula(
+//!         "data-id",
+//!         |task: &u32| task.to_string(),
+//!         vec![(container, tasks)],
+//!         Options::default(),
+//!     );
+//!
+//!     view! {
+//!         ul(r#ref=container) {
+//!             Keyed(
+//!                 list=tasks,
+//!                 view=|task| view! { li(data-id=task.to_string()) { (task) } },
+//!                 key=|task| *task,
+//!             )
+//!         }
+//!     }
+//! }
+//! ```
+use crate::{dragula_owned, Drake, MoveIntent, Options};
+use sycamore::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+
+struct SignalSync<T: 'static, F> {
+    attribute: String,
+    key: F,
+    bindings: Vec<(Element, Signal<Vec<T>>)>,
+}
+
+impl<T, F> SignalSync<T, F>
+where
+    T: Clone + 'static,
+    F: Fn(&T) -> String,
+{
+    fn signal(&self, container: &Element) -> Option<Signal<Vec<T>>> {
+        self.bindings
+            .iter()
+            .find(|(el, _)| el == container)
+            .map(|(_, signal)| *signal)
+    }
+
+    /// Moves the entry of the item described by `intent` between the bound
+    /// signals
+    fn apply(&self, intent: MoveIntent) {
+        let key = match intent.item.get_attribute(&self.attribute) {
+            Some(key) => key,
+            None => return,
+        };
+        let from = match self.signal(&intent.from_container) {
+            Some(from) => from,
+            None => return,
+        };
+        let position = from.with_untracked(|items| {
+            items.iter().position(|item| (self.key)(item) == key)
+        });
+        let position = match position {
+            Some(position) => position,
+            None => return,
+        };
+
+        let item = if intent.copy {
+            from.with_untracked(|items| items[position].clone())
+        } else {
+            from.update(|items| items.remove(position))
+        };
+
+        let to = intent.to_container.as_ref().and_then(|to| self.signal(to));
+        if let (Some(to), Some(to_index)) = (to, intent.to_index) {
+            to.update(|items| items.insert(to_index.min(items.len()), item));
+        }
+    }
+}
+
+/// Makes the items of the containers behind `containers` draggable once they
+/// are mounted, keeping the entries of each container's signal in the same
+/// order as its items.
+///
+/// `attribute` names the attribute holding the key of each item, and `key`
+/// returns the key of an entry. Items without the attribute, or dropped into
+/// a container that is not bound, are left alone. Copies, as configured
+/// through [`Options::copy`](crate::Options::copy), clone the entry into the
+/// target signal. Removed items have their entry removed.
+///
+/// Returns a signal holding the `Drake`, which is `None` until the
+/// containers are mounted. The `Drake` is destroyed, and its closures freed,
+/// when the current reactive scope is disposed.
+pub fn use_dragula<T, F>(
+    attribute: &str,
+    key: F,
+    containers: Vec<(NodeRef, Signal<Vec<T>>)>,
+    options: Options,
+) -> ReadSignal<Option<Drake>>
+where
+    T: Clone + 'static,
+    F: Fn(&T) -> String + 'static,
+{
+    let attribute = String::from(attribute);
+    let drake = create_signal(None);
+
+    on_mount(move || {
+        let bindings: Option<Vec<_>> = containers
+            .iter()
+            .map(|(node_ref, signal)| {
+                let el: Element = node_ref.try_get()?.unchecked_into();
+                Some((el, *signal))
+            })
+            .collect();
+
+        if let Some(bindings) = bindings {
+            drake.set(Some(bind(&attribute, key, bindings, options)));
+        }
+    });
+
+    *drake
+}
+
+/// Activates Dragula on the bound containers, and destroys it when the
+/// current reactive scope is disposed
+fn bind<T, F>(
+    attribute: &str,
+    key: F,
+    bindings: Vec<(Element, Signal<Vec<T>>)>,
+    options: Options,
+) -> Drake
+where
+    T: Clone + 'static,
+    F: Fn(&T) -> String + 'static,
+{
+    let containers: Vec<_> =
+        bindings.iter().map(|(el, _)| el.clone()).collect();
+    let sync = SignalSync {
+        attribute: String::from(attribute),
+        key,
+        bindings,
+    };
+
+    let mut drake = dragula_owned(&containers, options);
+    let listener = drake.controlled(move |intent| sync.apply(intent));
+    drake.keep(listener);
+
+    let handle = Drake::clone(&drake);
+    on_cleanup(move || drop(drake));
+    handle
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,197 @@
+use super::*;
+use crate::options::CopyValue;
+use crate::test_utils::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn signals(containers: u32, items: u32) -> Vec<Signal<Vec<String>>> {
+    (0..containers)
+        .map(|i| {
+            let items = (0..items).map(|j| format!("{}_{}", i, j)).collect();
+            create_signal(items)
+        })
+        .collect()
+}
+
+fn bind_all(
+    containers: &[Element],
+    signals: &[Signal<Vec<String>>],
+    options: Options,
+) -> Drake {
+    let bindings = containers.iter().cloned().zip(signals.iter().copied());
+    bind("data-id", String::clone, bindings.collect(), options)
+}
+
+/// Drags `item` into `target` before `sibling`, then drops it
+fn drag_into(
+    drake: &mut Drake,
+    item: &Element,
+    target: &Element,
+    sibling: Option<&Element>,
+) {
+    drake.start(item);
+    target
+        .insert_before(item, sibling.map(|s| s.as_ref()))
+        .unwrap();
+    drake.end();
+}
+
+#[wasm_bindgen_test]
+fn drop_moves_between_signals() {
+    console_error_panic_hook::set_once();
+
+    let html = keyed_containers(2, 3);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+
+        let root = create_root(|| {
+            let signals = signals(2, 3);
+            let mut drake = bind_all(&containers, &signals, Options::default());
+
+            let item = containers[0].first_element_child().unwrap();
+            let sibling = containers[1].children().item(1).unwrap();
+            drag_into(&mut drake, &item, &containers[1], Some(&sibling));
+
+            // The DOM is left for Sycamore to update
+            assert_eq!(containers[0].first_element_child(), Some(item));
+            assert_eq!(signals[0].get_clone(), vec!["0_1", "0_2"]);
+            assert_eq!(
+                signals[1].get_clone(),
+                vec!["1_0", "0_0", "1_1", "1_2"]
+            );
+        });
+
+        root.dispose();
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn drop_reorders_signal() {
+    console_error_panic_hook::set_once();
+
+    let html = keyed_containers(1, 3);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+
+        let root = create_root(|| {
+            let signals = signals(1, 3);
+            let mut drake = bind_all(&containers, &signals, Options::default());
+
+            let item = containers[0].first_element_child().unwrap();
+            drag_into(&mut drake, &item, &containers[0], None);
+
+            assert_eq!(containers[0].first_element_child(), Some(item));
+            assert_eq!(signals[0].get_clone(), vec!["0_1", "0_2", "0_0"]);
+        });
+
+        root.dispose();
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn copy_clones_entry() {
+    console_error_panic_hook::set_once();
+
+    let html = keyed_containers(2, 1);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+
+        let root = create_root(|| {
+            let signals = signals(2, 1);
+            let options = Options {
+                copy: CopyValue::Bool(true),
+                ..Options::default()
+            };
+            let mut drake = bind_all(&containers, &signals, options);
+
+            let copies = Rc::new(RefCell::new(Vec::new()));
+            let copies_ref = copies.clone();
+            let _cloned = drake.on_cloned(move |clone, _original, _kind| {
+                copies_ref.borrow_mut().push(Element::from(clone));
+            });
+
+            let item = containers[0].first_element_child().unwrap();
+            drake.start(&item);
+            let copy = copies.borrow().first().unwrap().clone();
+            containers[1].append_child(&copy).unwrap();
+            drake.end();
+
+            assert_eq!(containers[1].child_element_count(), 1);
+            assert_eq!(signals[0].get_clone(), vec!["0_0"]);
+            assert_eq!(signals[1].get_clone(), vec!["1_0", "0_0"]);
+        });
+
+        root.dispose();
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn remove_removes_entry() {
+    console_error_panic_hook::set_once();
+
+    let html = keyed_containers(1, 3);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+
+        let root = create_root(|| {
+            let signals = signals(1, 3);
+            let mut drake = bind_all(&containers, &signals, Options::default());
+
+            let item = containers[0].children().item(1).unwrap();
+            drake.start(&item);
+            drake.remove();
+
+            assert_eq!(containers[0].children().item(1), Some(item));
+            assert_eq!(signals[0].get_clone(), vec!["0_0", "0_2"]);
+        });
+
+        root.dispose();
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn disposing_scope_destroys_drake() {
+    console_error_panic_hook::set_once();
+
+    let html = keyed_containers(1, 1);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let drake = Rc::new(RefCell::new(None));
+
+        let drake_ref = drake.clone();
+        let root = create_root(|| {
+            let signals = signals(1, 1);
+            let drake = bind_all(&containers, &signals, Options::default());
+            *drake_ref.borrow_mut() = Some(drake);
+        });
+
+        let mut drake = drake.borrow_mut().take().unwrap();
+        let destroyed = Rc::new(RefCell::new(false));
+        let destroyed_ref = destroyed.clone();
+        let _listener = drake.on_destroy(move || {
+            *destroyed_ref.borrow_mut() = true;
+        });
+
+        root.dispose();
+
+        assert!(*destroyed.borrow());
+    };
+
+    run_dom_test(test, &html);
+}
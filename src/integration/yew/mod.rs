@@ -0,0 +1,160 @@
+//! Hooks and components for using Dragula with [Yew](https://yew.rs)
+//!
+//! Requires that feature `yew` be turned on.
+//!
+//! The [`use_dragula`](use_dragula) hook creates a [`Drake`](crate::Drake)
+//! once the containers behind its `NodeRef`s are mounted, and destroys it
+//! when the component is unmounted. The `Drake` runs in
+//! [controlled mode](crate::Drake::controlled), so Yew keeps ownership of the
+//! DOM: every drop is reverted and handed to a callback as a
+//! [`MoveIntent`](crate::MoveIntent), which the component applies to its own
+//! state.
+//!
+//! ```no_run
+//! use dragula::*;
+//! use dragula::integration::yew::use_dragula;
+//! use yew::prelude::*;
+//!
+//! #[function_component]
+//! fn Tasks() -> Html {
+//!     let tasks = use_state(|| vec!["Write", "Review", "Ship"]);
+//!     let container = use_node_ref();
+//!
+//!     let on_move = {
+//!         let tasks = tasks.clone();
+//!         Callback::from(move |intent: MoveIntent| {
+//!             if let Some(to_index) = intent.to_index {
+//!                 let mut new_tasks = (*tasks).clone();
+//!                 let task = new_tasks.remove(intent.from_index);
+//!                 new_tasks.insert(to_index, task);
+//!                 tasks.set(new_tasks);
+//!             }
+//!         })
+//!     };
+//!
+//!     use_dragula(vec![container.clone()], Options::default(), on_move);
+//!
+//!     html! {
+//!         <ul ref={container}>
+//!             { for tasks.iter().map(|task| html! { <li key={*task}>{ task }</li> }) }
+//!         </ul>
+//!     }
+//! }
+//! ```
+use crate::{dragula_owned, Drake, MoveIntent, Options, OwnedDrake};
+use std::cell::RefCell;
+use std::rc::Rc;
+use web_sys::Element;
+use yew::prelude::*;
+
+/// Gives access to the [`Drake`](crate::Drake) created by
+/// [`use_dragula`](use_dragula)
+///
+/// Handles are cheap to clone, and two handles are equal when they refer to
+/// the same hook.
+#[derive(Clone, Default)]
+pub struct DrakeHandle {
+    drake: Rc<RefCell<Option<OwnedDrake>>>,
+}
+
+impl DrakeHandle {
+    /// Returns the `Drake`, or `None` before the containers are mounted and
+    /// after the component is unmounted
+    pub fn drake(&self) -> Option<Drake> {
+        self.drake
+            .borrow()
+            .as_ref()
+            .map(|drake| Drake::clone(drake))
+    }
+}
+
+impl PartialEq for DrakeHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.drake, &other.drake)
+    }
+}
+
+/// Activates Dragula on the containers behind `container_refs` once they are
+/// mounted, in [controlled mode](crate::Drake::controlled).
+///
+/// Every drop or removal is reverted, and passed to `on_move` as a
+/// [`MoveIntent`](crate::MoveIntent) for the component to apply to its
+/// state. The latest `on_move` is always used, so it may be recreated on
+/// every render.
+///
+/// `container_refs` and `options` are only read on the first render. The
+/// `Drake` is destroyed, and its closures freed, when the component is
+/// unmounted.
+#[hook]
+pub fn use_dragula(
+    container_refs: Vec<NodeRef>,
+    options: Options,
+    on_move: Callback<MoveIntent>,
+) -> DrakeHandle {
+    let handle = use_memo((), |_| DrakeHandle::default());
+    let on_move_ref = use_mut_ref(|| on_move.clone());
+    *on_move_ref.borrow_mut() = on_move;
+
+    {
+        let handle = (*handle).clone();
+        use_effect_with((), move |_| {
+            let containers: Vec<Element> =
+                container_refs.iter().filter_map(NodeRef::cast).collect();
+
+            let mut drake = dragula_owned(&containers, options);
+            let listener = drake.controlled(move |intent| {
+                let on_move = on_move_ref.borrow().clone();
+                on_move.emit(intent);
+            });
+            drake.keep(listener);
+            *handle.drake.borrow_mut() = Some(drake);
+
+            move || {
+                let drake = handle.drake.borrow_mut().take();
+                drop(drake);
+            }
+        });
+    }
+
+    (*handle).clone()
+}
+
+/// Properties of the [`Dragula`](Dragula) component
+#[derive(Properties, PartialEq)]
+pub struct DragulaProps {
+    /// The draggable items
+    #[prop_or_default]
+    pub children: Html,
+    /// Receives every drop or removal, which must be applied to the state
+    /// that renders `children`
+    pub on_move: Callback<MoveIntent>,
+    /// Classes of the container element
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// A single container whose children can be reordered by dragging
+///
+/// Renders a `div` around `children` and activates Dragula on it through
+/// [`use_dragula`](use_dragula), with the default
+/// [`Options`](crate::Options). Items cannot be dragged between separate
+/// `Dragula` components; use the hook directly for that.
+#[function_component]
+pub fn Dragula(props: &DragulaProps) -> Html {
+    let container = use_node_ref();
+
+    use_dragula(
+        vec![container.clone()],
+        Options::default(),
+        props.on_move.clone(),
+    );
+
+    html! {
+        <div ref={container} class={props.class.clone()}>
+            { props.children.clone() }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,92 @@
+use super::*;
+use std::time::Duration;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Properties, PartialEq)]
+struct ListProps {
+    on_handle: Callback<DrakeHandle>,
+}
+
+#[function_component]
+fn List(props: &ListProps) -> Html {
+    let items = use_state(|| vec!["a", "b", "c"]);
+    let container = use_node_ref();
+
+    let on_move = {
+        let items = items.clone();
+        Callback::from(move |intent: MoveIntent| {
+            let mut new_items = (*items).clone();
+            let item = new_items.remove(intent.from_index);
+            if let Some(to_index) = intent.to_index {
+                new_items.insert(to_index, item);
+            }
+            items.set(new_items);
+        })
+    };
+
+    let handle =
+        use_dragula(vec![container.clone()], Options::default(), on_move);
+    props.on_handle.emit(handle);
+
+    html! {
+        <div id="list" ref={container}>
+            { for items.iter().map(|item| html! { <div key={*item}>{ item }</div> }) }
+        </div>
+    }
+}
+
+fn text_of(container: &Element) -> String {
+    container.text_content().unwrap_or_default()
+}
+
+/// Lets Yew render and run its effects
+async fn settle() {
+    sleep(Duration::ZERO).await;
+}
+
+#[wasm_bindgen_test]
+async fn use_dragula_applies_moves_through_state() {
+    console_error_panic_hook::set_once();
+
+    let handle = Rc::new(RefCell::new(None));
+    let handle_ref = handle.clone();
+    let on_handle = Callback::from(move |handle| {
+        *handle_ref.borrow_mut() = Some(handle);
+    });
+
+    let doc = web_sys::window().unwrap().document().unwrap();
+    let root = doc.create_element("div").unwrap();
+    doc.body().unwrap().append_child(&root).unwrap();
+
+    let app = yew::Renderer::<List>::with_root_and_props(
+        root.clone(),
+        ListProps { on_handle },
+    )
+    .render();
+    settle().await;
+
+    let handle: DrakeHandle = handle.borrow().clone().unwrap();
+    let mut drake = handle.drake().expect("Drake was not created");
+
+    let container = root.first_element_child().unwrap();
+    let item = container.first_element_child().unwrap();
+    assert_eq!(text_of(&container), "abc");
+
+    drake.start(&item);
+    container.append_child(&item).unwrap();
+    drake.end();
+
+    // The DOM is reverted until Yew renders the new state
+    assert_eq!(text_of(&container), "abc");
+    settle().await;
+    assert_eq!(text_of(&container), "bca");
+
+    app.destroy();
+    settle().await;
+    assert!(handle.drake().is_none());
+
+    root.remove();
+}
@@ -0,0 +1,341 @@
+//! Drag and drop with the keyboard
+//!
+//! Requires that feature `web-sys` be turned on.
+//!
+//! A [`KeyboardController`](KeyboardController) lets items be moved without a
+//! pointer, building on [`Drake::start`](crate::Drake::start):
+//!
+//! - **Space** or **Enter** picks up the focused item
+//! - The **arrow keys** along the [`direction`](crate::Options::direction)
+//!   of the containers move it within its container, and the other two arrow
+//!   keys move it to the previous or next container
+//! - **Enter** drops it where it is, or cancels the drag if it is a copy
+//!   that was not moved yet
+//! - **Escape** puts it back where it was picked up
+//!
+//! Moves are only made into places allowed by
+//! [`Options::accepts`](crate::Options::accepts). Every move emits a `shadow`
+//! event, and dropping or cancelling emits the same `drop` or `cancel` events
+//! as a mouse drag, so listeners on the `Drake` work for both.
+//!
+//! Dragula does not know about moves made with the keyboard, so the
+//! `sibling` of their `drop` event is still the one the item was picked up
+//! next to. Listeners that need it should read the
+//! [`next_element_sibling`](web_sys::Element::next_element_sibling) of the
+//! dropped item instead.
+//!
+//! Items must be focusable, for example by giving them a `tabindex`.
+//! Containers can only be reached with the arrow keys if they were passed to
+//! the `Drake` directly, rather than matched by
+//! [`Options::is_container`](crate::Options::is_container).
+//!
+//! ```no_run
+//! use dragula::*;
+//! use dragula::keyboard::KeyboardController;
+//!
+//! let doc = web_sys::window().unwrap().document().unwrap();
+//! let element = doc.get_element_by_id("drag-container").unwrap();
+//!
+//! let mut drake = dragula(&[element]);
+//! let keyboard = KeyboardController::new(&mut drake, &doc);
+//!
+//! //--snip--
+//! ```
+use crate::closure::{self, OwnedClosure};
+use crate::drake::{insert_before, DragState};
+use crate::event::*;
+use crate::options::Direction;
+use crate::{Drake, ListenerHandle};
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, EventTarget, HtmlElement, KeyboardEvent};
+
+const KEYDOWN: &str = "keydown";
+const SHADOW_EVENT: &str = "shadow";
+
+/// An item that was picked up with the keyboard
+struct Grab {
+    item: Element,
+    source: Element,
+    /// The copy being moved instead of `item`, when copying
+    copy: Option<Element>,
+}
+
+impl Grab {
+    fn moving(&self) -> &Element {
+        self.copy.as_ref().unwrap_or(&self.item)
+    }
+}
+
+#[derive(Default)]
+struct KeyboardState {
+    grab: Option<Grab>,
+    /// Filled in by the events emitted while `start` runs
+    drag: DragState,
+}
+
+impl KeyboardState {
+    fn track(&mut self, event: DragulaEvent<Element>) {
+        self.drag.track(&event);
+        if let DragulaEvent::DragEnd(_) = event {
+            self.grab = None;
+        }
+    }
+}
+
+/// Which way an arrow key moves the item
+struct Step {
+    across: bool,
+    forward: bool,
+}
+
+impl Step {
+    fn from_key(key: &str, direction: Direction) -> Option<Step> {
+        let (back, forward, across_back, across_forward) = match direction {
+            Direction::Vertical => {
+                ("ArrowUp", "ArrowDown", "ArrowLeft", "ArrowRight")
+            }
+            Direction::Horizontal => {
+                ("ArrowLeft", "ArrowRight", "ArrowUp", "ArrowDown")
+            }
+        };
+
+        let (across, forward) = match key {
+            k if k == back => (false, false),
+            k if k == forward => (false, true),
+            k if k == across_back => (true, false),
+            k if k == across_forward => (true, true),
+            _ => return None,
+        };
+        Some(Step { across, forward })
+    }
+}
+
+/// The element children of `container`, leaving out `except`
+fn children_except(container: &Element, except: &Element) -> Vec<Element> {
+    let mut children = Vec::new();
+    let mut child = container.first_element_child();
+    while let Some(el) = child {
+        if el != *except {
+            children.push(el.clone());
+        }
+        child = el.next_element_sibling();
+    }
+    children
+}
+
+fn focus(el: &Element) {
+    if let Some(el) = el.dyn_ref::<HtmlElement>() {
+        // Focusing can only fail for elements that are not focusable
+        let _ = el.focus();
+    }
+}
+
+struct Keyboard {
+    drake: Drake,
+    state: Rc<RefCell<KeyboardState>>,
+}
+
+impl Keyboard {
+    /// Returns whether the key press was handled
+    fn handle(&mut self, event: &KeyboardEvent) -> bool {
+        if event.ctrl_key() || event.alt_key() || event.meta_key() {
+            return false;
+        }
+
+        let key = event.key();
+        let grabbed = self.state.borrow().grab.is_some();
+
+        if !grabbed {
+            return match key.as_str() {
+                " " | "Enter" => self.pick_up(event),
+                _ => false,
+            };
+        }
+
+        match key.as_str() {
+            "Enter" => self.put_down(),
+            "Escape" => self.drake.cancel_with_revert(true),
+            // Keeps the page from scrolling while an item is held
+            " " => {}
+            key => match Step::from_key(key, self.drake.direction()) {
+                Some(step) => self.step(step),
+                None => return false,
+            },
+        }
+        true
+    }
+
+    fn pick_up(&mut self, event: &KeyboardEvent) -> bool {
+        let target = match event.target() {
+            Some(target) => target.unchecked_into::<Element>(),
+            None => return false,
+        };
+        if self.drake.dragging() || !self.drake.can_move(&target) {
+            return false;
+        }
+
+        self.drake.start(&target);
+
+        let mut state = self.state.borrow_mut();
+        let grab = match &state.drag.origin {
+            Some(origin) => Grab {
+                item: origin.item.clone(),
+                source: origin.container.clone(),
+                copy: state.drag.copy.clone(),
+            },
+            None => return false,
+        };
+        state.grab = Some(grab);
+        true
+    }
+
+    fn put_down(&mut self) {
+        let placed = self
+            .state
+            .borrow()
+            .grab
+            .as_ref()
+            .is_some_and(|grab| grab.moving().parent_element().is_some());
+
+        // A copy that was never moved has nowhere to be dropped
+        if placed {
+            self.drake.end();
+        } else {
+            self.drake.cancel_with_revert(true);
+        }
+    }
+
+    fn step(&mut self, step: Step) {
+        let state = self.state.borrow();
+        let grab = match &state.grab {
+            Some(grab) => grab,
+            None => return,
+        };
+        let moving = grab.moving().clone();
+
+        // A copy has not been placed anywhere until it is first moved, so it
+        // starts out next to the item
+        let placed = moving.parent_element().is_some();
+        let anchor = if placed { &moving } else { &grab.item };
+        let container = match anchor.parent_element() {
+            Some(container) => container,
+            None => return,
+        };
+        let index = children_except(&container, &moving)
+            .iter()
+            .take_while(|child| *child != anchor)
+            .count();
+
+        let (target, index) = if step.across {
+            let containers = self.drake.container_elements();
+            let position = containers.iter().position(|c| *c == container);
+            let next = match (position, step.forward) {
+                (Some(position), true) => position + 1,
+                (Some(position), false) if position > 0 => position - 1,
+                _ => return,
+            };
+            match containers.get(next) {
+                Some(target) => (target.clone(), index),
+                None => return,
+            }
+        } else if step.forward {
+            (container, index + 1)
+        } else if !placed {
+            (container, index)
+        } else if index > 0 {
+            (container, index - 1)
+        } else {
+            return;
+        };
+
+        let siblings = children_except(&target, &moving);
+        if !step.across && index > siblings.len() {
+            return;
+        }
+        let sibling = siblings.get(index.min(siblings.len()));
+        let sibling_value = sibling.map_or(JsValue::NULL, JsValue::from);
+
+        let accepted = self.drake.accepts_impl(
+            moving.as_ref(),
+            target.as_ref(),
+            grab.source.as_ref(),
+            &sibling_value,
+        );
+        if !accepted {
+            return;
+        }
+
+        insert_before(&target, &moving, sibling);
+        focus(&moving);
+
+        let source = grab.source.clone();
+        drop(state);
+        self.drake.emit_3(
+            SHADOW_EVENT,
+            moving.as_ref(),
+            target.as_ref(),
+            source.as_ref(),
+        );
+    }
+}
+
+/// Lets the items of a [`Drake`](crate::Drake) be dragged and dropped with
+/// the keyboard
+///
+/// See the [module documentation](self) for the keys that are handled. The
+/// controller stops handling key presses once it is dropped.
+pub struct KeyboardController {
+    target: EventTarget,
+    keydown: OwnedClosure,
+    _listener: ListenerHandle,
+}
+
+impl KeyboardController {
+    /// Handles key presses on `target` for `drake`. `target` is usually the
+    /// document, or an element containing all of the containers.
+    pub fn new(drake: &mut Drake, target: &EventTarget) -> Self {
+        let state = Rc::new(RefCell::new(KeyboardState::default()));
+
+        let state_ref = state.clone();
+        let listener = drake
+            .on_element_event(move |event| state_ref.borrow_mut().track(event));
+
+        let mut keyboard = Keyboard {
+            drake: drake.clone(),
+            state,
+        };
+        let keydown = closure::to_js_1(move |event: JsValue| {
+            let event: KeyboardEvent = event.unchecked_into();
+            if !event.default_prevented() && keyboard.handle(&event) {
+                event.prevent_default();
+            }
+        });
+
+        // Adding a listener cannot fail for a function
+        let _ = target.add_event_listener_with_callback(
+            KEYDOWN,
+            keydown.function().unchecked_ref(),
+        );
+
+        KeyboardController {
+            target: target.clone(),
+            keydown,
+            _listener: listener,
+        }
+    }
+}
+
+impl Drop for KeyboardController {
+    fn drop(&mut self) {
+        let _ = self.target.remove_event_listener_with_callback(
+            KEYDOWN,
+            self.keydown.function().unchecked_ref(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,236 @@
+use super::*;
+use crate::history::History;
+use crate::options::CopyValue;
+use crate::test_utils::*;
+use crate::{dragula, dragula_options, Options};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn keys_move_item_within_container_and_drop() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(1, 3);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let mut drake = dragula(&containers);
+        let _keyboard = KeyboardController::new(&mut drake, element);
+
+        let drops = Rc::new(RefCell::new(Vec::new()));
+        let drops_ref = drops.clone();
+        let _listener = drake.on_element_event(move |event| {
+            if let DragulaEvent::Drop(drop) = event {
+                drops_ref.borrow_mut().push(drop);
+            }
+        });
+
+        let container = &containers[0];
+        let item = container.first_element_child().unwrap();
+
+        press(&item, " ");
+        assert!(drake.dragging());
+
+        press(&item, "ArrowDown");
+        press(&item, "ArrowDown");
+        assert_eq!(container.last_element_child(), Some(item.clone()));
+
+        press(&item, "Enter");
+        assert!(!drake.dragging());
+
+        let drops = drops.borrow();
+        assert_eq!(drops.len(), 1);
+        assert_eq!(drops[0].el, item);
+        assert_eq!(drops[0].target, *container);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn arrows_across_direction_change_container() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 2);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let mut drake = dragula(&containers);
+        let _keyboard = KeyboardController::new(&mut drake, element);
+
+        let item = containers[0].children().item(1).unwrap();
+
+        press(&item, "Enter");
+        press(&item, "ArrowRight");
+        assert_eq!(item.parent_element(), Some(containers[1].clone()));
+        assert_eq!(containers[1].children().item(1), Some(item.clone()));
+
+        press(&item, "ArrowRight");
+        assert_eq!(item.parent_element(), Some(containers[1].clone()));
+
+        press(&item, "Enter");
+        assert!(!drake.dragging());
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn escape_reverts_and_cancels() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 2);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let mut drake = dragula(&containers);
+        let _keyboard = KeyboardController::new(&mut drake, element);
+
+        let cancels = Rc::new(RefCell::new(0));
+        let cancels_ref = cancels.clone();
+        let _listener = drake.on_cancel(move |_el, _container, _source| {
+            *cancels_ref.borrow_mut() += 1;
+        });
+
+        let item = containers[0].first_element_child().unwrap();
+
+        press(&item, " ");
+        press(&item, "ArrowRight");
+        press(&item, "Escape");
+
+        assert!(!drake.dragging());
+        assert_eq!(containers[0].first_element_child(), Some(item));
+        assert_eq!(*cancels.borrow(), 1);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn enter_cancels_unmoved_copy() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(1, 2);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let mut drake = dragula_options(
+            &containers,
+            Options {
+                copy: CopyValue::Bool(true),
+                ..Options::default()
+            },
+        );
+        let _keyboard = KeyboardController::new(&mut drake, element);
+
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let events_ref = events.clone();
+        let _listener = drake.on_element_event(move |event| match event {
+            DragulaEvent::Drop(_) => events_ref.borrow_mut().push("drop"),
+            DragulaEvent::Cancel(_) => events_ref.borrow_mut().push("cancel"),
+            _ => {}
+        });
+
+        let item = containers[0].first_element_child().unwrap();
+        press(&item, " ");
+        press(&item, "Enter");
+
+        assert!(!drake.dragging());
+        assert_eq!(*events.borrow(), ["cancel"]);
+        assert_eq!(containers[0].child_element_count(), 2);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn history_redoes_keyboard_drop_before_real_sibling() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(1, 3);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let container = &containers[0];
+        let mut drake = dragula(&containers);
+        let history = History::new(&mut drake);
+        let _keyboard = KeyboardController::new(&mut drake, element);
+
+        let item = container.first_element_child().unwrap();
+        press(&item, " ");
+        press(&item, "ArrowDown");
+        press(&item, "Enter");
+
+        let moved = ["drag_0_1", "drag_0_0", "drag_0_2"];
+        assert_eq!(ids(container), moved);
+        let operation = &history.operations()[0];
+        assert_eq!(
+            operation
+                .target_sibling
+                .as_ref()
+                .map(Element::id)
+                .as_deref(),
+            Some("drag_0_2")
+        );
+
+        assert!(history.undo());
+        assert_eq!(ids(container), ["drag_0_0", "drag_0_1", "drag_0_2"]);
+        assert!(history.redo());
+        assert_eq!(ids(container), moved);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn moves_honour_accepts() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 2);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let source = JsValue::from(&containers[0]);
+        let options = Options {
+            accepts: Box::new(move |_el, target, _source, _sibling| {
+                target == source
+            }),
+            ..Options::default()
+        };
+        let mut drake = dragula_options(&containers, options);
+        let _keyboard = KeyboardController::new(&mut drake, element);
+
+        let item = containers[0].first_element_child().unwrap();
+
+        press(&item, " ");
+        press(&item, "ArrowRight");
+        assert_eq!(item.parent_element(), Some(containers[0].clone()));
+
+        press(&item, "ArrowDown");
+        assert_eq!(containers[0].last_element_child(), Some(item.clone()));
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn dropped_controller_ignores_keys() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(1, 2);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let mut drake = dragula(&containers);
+        let keyboard = KeyboardController::new(&mut drake, element);
+
+        drop(keyboard);
+
+        let item = containers[0].first_element_child().unwrap();
+        press(&item, " ");
+        assert!(!drake.dragging());
+    };
+
+    run_dom_test(test, &html);
+}
@@ -42,19 +42,80 @@
 //! ```
 //!
 //! ## `cargo` Features
-//! - **js-sys**: On by default. Can be used to toggle dependencies on the `js-sys`
-//!   crate. Most of this crate relies solely on `wasm-bindgen`, so disabling this
-//!   feature currently just prevents you from getting containers on an existing
-//!   Drake. The main reason you might want to disable this would be to improve compile
-//!   times.
+//! - **js-sys**: Off by default. No longer has any effect, since the whole crate now
+//!   relies solely on `wasm-bindgen`. It is only kept so that manifests that turn it
+//!   on keep building.
+//! - **web-sys**: Off by default. Adds everything that works with `web_sys::Element`
+//!   rather than `JsValue`:
+//!   - the `typed` module, which wraps closures taking `Element` arguments for use in
+//!     `Options`
+//!   - the `sortable` module, which keeps Rust models in sync with the order of items
+//!   - the `keyboard` module, for dragging and dropping with the keyboard
+//!   - the `accessibility` module, which announces drags to screen readers
+//!   - the `predicates` module, with composable checks for the closures in
+//!     `Options`
+//!   - the `groups` module, which derives `accepts` and `copy` from named groups
+//!     of containers
+//!   - the `capacity` module, which limits how many items a container can hold
+//!   - the `history` module, for undoing and redoing drops and removals
+//!   - methods on `Drake` that work with `Element`, such as `controlled` for use with
+//!     virtual DOM frameworks
+//!   - selector-based shortcuts on `OptionsBuilder`
+//! - **futures**: Off by default. Adds methods on `Drake`, such as `drops` and `events`,
+//!   that return a `futures::Stream` of events, along with `next_drop` for awaiting a
+//!   single drop.
+//! - **yew**: Off by default. Adds the `integration::yew` module, with a `use_dragula`
+//!   hook and a `Dragula` component that leave the DOM to Yew and report drops as
+//!   callbacks. Turns on `web-sys`.
+//! - **leptos**: Off by default. Adds the `integration::leptos` module, which binds
+//!   containers to `RwSignal`s of item keys through `use_dragula` or the `sortable`
+//!   directive. Turns on `web-sys`.
+//! - **dioxus**: Off by default. Adds the `integration::dioxus` module, with a
+//!   `use_dragula` hook that registers containers as they are mounted and passes
+//!   `drop`, `cancel` and `remove` events to event handlers. Turns on `web-sys`.
+//! - **sycamore**: Off by default. Adds the `integration::sycamore` module, whose
+//!   `use_dragula` keeps a `Signal<Vec<T>>` per container in the order of its keyed
+//!   items. Turns on `web-sys`.
+//! - **serde**: Off by default. Implements `Serialize` and `Deserialize` for `Layout`,
+//!   the item order returned by `Drake::serialize_order`, and adds the `persistence`
+//!   module, which saves that order to `localStorage` or another store. Turns on
+//!   `web-sys`.
 #![doc(
     html_favicon_url = "https://bevacqua.github.io/dragula/resources/icon.svg"
 )]
 #![doc(html_logo_url = "https://bevacqua.github.io/dragula/resources/icon.svg")]
 
+#[cfg(feature = "web-sys")]
+pub mod accessibility;
+mod animation;
+mod autoscroll;
+#[cfg(feature = "web-sys")]
+pub mod capacity;
 mod dragula;
 mod drake;
+pub mod event;
+#[cfg(feature = "web-sys")]
+pub mod groups;
+#[cfg(feature = "web-sys")]
+pub mod history;
+#[cfg(any(
+    feature = "dioxus",
+    feature = "leptos",
+    feature = "sycamore",
+    feature = "yew"
+))]
+pub mod integration;
+#[cfg(feature = "web-sys")]
+pub mod keyboard;
 pub mod options;
+#[cfg(feature = "serde")]
+pub mod persistence;
+#[cfg(feature = "web-sys")]
+pub mod predicates;
+#[cfg(feature = "web-sys")]
+pub mod sortable;
+#[cfg(feature = "web-sys")]
+pub mod typed;
 
 // Helpers
 mod closure;
@@ -63,7 +124,18 @@ mod closure;
 pub use crate::dragula::*;
 
 #[doc(inline)]
-pub use drake::Drake;
+pub use drake::{Drake, ListenerHandle, OwnedDrake};
+
+#[cfg(feature = "futures")]
+#[doc(inline)]
+pub use drake::EventStream;
+
+#[cfg(feature = "web-sys")]
+#[doc(inline)]
+pub use drake::{Layout, MoveIntent, OrderError};
+
+#[doc(inline)]
+pub use event::DragulaEvent;
 
 #[doc(inline)]
 pub use options::Options;
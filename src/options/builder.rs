@@ -0,0 +1,246 @@
+use super::{Animation, AutoScroll, CopyValue, Direction, Options};
+#[cfg(feature = "web-sys")]
+use crate::groups::Groups;
+#[cfg(feature = "serde")]
+use crate::persistence::Persistence;
+#[cfg(feature = "web-sys")]
+use crate::typed;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+#[cfg(feature = "web-sys")]
+use web_sys::Element;
+
+/// Builds an [`Options`](crate::Options) instance one option at a time
+///
+/// Created with [`Options::builder`](crate::Options::builder). Any option
+/// that is not set keeps its [default](crate::Options::default) value.
+///
+/// With feature `web-sys` turned on, the builder also offers selector-based
+/// shortcuts for the most common closures, so that no closure has to be
+/// written by hand. Selectors that fail to parse never match.
+///
+/// ### Example:
+#[cfg_attr(feature = "web-sys", doc = "```no_run")]
+#[cfg_attr(not(feature = "web-sys"), doc = "```ignore")]
+/// use dragula::*;
+/// use dragula::options::{Animation, AutoScroll, Direction};
+/// # use wasm_bindgen::JsValue;
+///
+/// # let element = JsValue::TRUE;
+/// //--snip--
+///
+/// let options = Options::builder()
+///     .containers_matching(".lane")
+///     .handle(".grip")
+///     .invalid_selector("input, a")
+///     .copy_from(".palette")
+///     .direction(Direction::Horizontal)
+///     .revert_on_spill(true)
+///     .autoscroll(AutoScroll::default())
+///     .animation(Animation::default())
+///     .build();
+///
+/// let drake = dragula_options(&[element], options);
+///
+/// //--snip--
+/// ```
+#[derive(Default)]
+pub struct OptionsBuilder {
+    options: Options,
+}
+
+impl OptionsBuilder {
+    /// Sets [`Options::is_container`](crate::Options::is_container)
+    pub fn is_container<F>(mut self, is_container: F) -> Self
+    where
+        F: FnMut(JsValue) -> bool + 'static,
+    {
+        self.options.is_container = Box::new(is_container);
+        self
+    }
+
+    /// Sets [`Options::moves`](crate::Options::moves)
+    pub fn moves<F>(mut self, moves: F) -> Self
+    where
+        F: FnMut(JsValue, JsValue, JsValue, JsValue) -> bool + 'static,
+    {
+        self.options.moves = Box::new(moves);
+        self
+    }
+
+    /// Sets [`Options::accepts`](crate::Options::accepts)
+    pub fn accepts<F>(mut self, accepts: F) -> Self
+    where
+        F: FnMut(JsValue, JsValue, JsValue, JsValue) -> bool + 'static,
+    {
+        self.options.accepts = Box::new(accepts);
+        self
+    }
+
+    /// Sets [`Options::invalid`](crate::Options::invalid)
+    pub fn invalid<F>(mut self, invalid: F) -> Self
+    where
+        F: FnMut(JsValue, JsValue) -> bool + 'static,
+    {
+        self.options.invalid = Box::new(invalid);
+        self
+    }
+
+    /// Sets [`Options::copy`](crate::Options::copy)
+    pub fn copy(mut self, copy: CopyValue) -> Self {
+        self.options.copy = copy;
+        self
+    }
+
+    /// Sets [`Options::copy_sort_source`](crate::Options::copy_sort_source)
+    pub fn copy_sort_source(mut self, copy_sort_source: bool) -> Self {
+        self.options.copy_sort_source = copy_sort_source;
+        self
+    }
+
+    /// Sets [`Options::revert_on_spill`](crate::Options::revert_on_spill)
+    pub fn revert_on_spill(mut self, revert_on_spill: bool) -> Self {
+        self.options.revert_on_spill = revert_on_spill;
+        self
+    }
+
+    /// Sets [`Options::remove_on_spill`](crate::Options::remove_on_spill)
+    pub fn remove_on_spill(mut self, remove_on_spill: bool) -> Self {
+        self.options.remove_on_spill = remove_on_spill;
+        self
+    }
+
+    /// Sets [`Options::direction`](crate::Options::direction)
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.options.direction = direction;
+        self
+    }
+
+    /// Sets [`Options::mirror_container`](crate::Options::mirror_container)
+    pub fn mirror_container<T>(mut self, mirror_container: &T) -> Self
+    where
+        T: JsCast,
+    {
+        self.options.mirror_container = JsValue::from(mirror_container);
+        self
+    }
+
+    /// Sets
+    /// [`Options::ignore_input_text_selection`](crate::Options::ignore_input_text_selection)
+    pub fn ignore_input_text_selection(
+        mut self,
+        ignore_input_text_selection: bool,
+    ) -> Self {
+        self.options.ignore_input_text_selection = ignore_input_text_selection;
+        self
+    }
+
+    /// Sets [`Options::slide_factor_x`](crate::Options::slide_factor_x)
+    pub fn slide_factor_x(mut self, slide_factor_x: i32) -> Self {
+        self.options.slide_factor_x = slide_factor_x;
+        self
+    }
+
+    /// Sets [`Options::slide_factor_y`](crate::Options::slide_factor_y)
+    pub fn slide_factor_y(mut self, slide_factor_y: i32) -> Self {
+        self.options.slide_factor_y = slide_factor_y;
+        self
+    }
+
+    /// Sets [`Options::autoscroll`](crate::Options::autoscroll)
+    pub fn autoscroll(mut self, autoscroll: AutoScroll) -> Self {
+        self.options.autoscroll = Some(autoscroll);
+        self
+    }
+
+    /// Sets [`Options::animation`](crate::Options::animation)
+    pub fn animation(mut self, animation: Animation) -> Self {
+        self.options.animation = Some(animation);
+        self
+    }
+
+    /// Sets [`Options::persistence`](crate::Options::persistence)
+    ///
+    /// Requires that feature `serde` be turned on
+    #[cfg(feature = "serde")]
+    pub fn persistence(mut self, persistence: Persistence) -> Self {
+        self.options.persistence = persistence.into();
+        self
+    }
+
+    /// Returns the [`Options`](crate::Options) that were built
+    pub fn build(self) -> Options {
+        self.options
+    }
+}
+
+#[cfg(feature = "web-sys")]
+fn matches(el: &Element, selector: &str) -> bool {
+    el.matches(selector).unwrap_or(false)
+}
+
+/// Whether `handle`, or one of its ancestors inside `el`, matches `selector`
+#[cfg(feature = "web-sys")]
+fn closest_within(el: &Element, handle: &Element, selector: &str) -> bool {
+    match handle.closest(selector) {
+        Ok(Some(closest)) => el.contains(Some(&closest)),
+        _ => false,
+    }
+}
+
+/// Selector-based shortcuts
+///
+/// Requires that feature `web-sys` be turned on
+#[cfg(feature = "web-sys")]
+impl OptionsBuilder {
+    /// Treats any element matching `selector` as a container, through
+    /// [`Options::is_container`](crate::Options::is_container)
+    pub fn containers_matching(self, selector: &str) -> Self {
+        let selector = String::from(selector);
+        self.is_container(typed::is_container(move |el| matches(el, &selector)))
+    }
+
+    /// Only starts a drag when the click lands on an element matching
+    /// `selector` inside the item, through
+    /// [`Options::moves`](crate::Options::moves)
+    pub fn handle(self, selector: &str) -> Self {
+        let selector = String::from(selector);
+        self.moves(typed::moves(move |el, _source, handle, _sibling| {
+            closest_within(el, handle, &selector)
+        }))
+    }
+
+    /// Prevents a drag from starting when the click lands on an element
+    /// matching `selector` inside the item, through
+    /// [`Options::invalid`](crate::Options::invalid)
+    pub fn invalid_selector(self, selector: &str) -> Self {
+        let selector = String::from(selector);
+        self.invalid(typed::invalid(move |el, handle| {
+            closest_within(el, handle, &selector)
+        }))
+    }
+
+    /// Sets [`Options::accepts`](crate::Options::accepts) from a closure
+    /// taking elements, with the signature `(el, target, source, sibling)`
+    pub fn accept_if<F>(self, accepts: F) -> Self
+    where
+        F: FnMut(&Element, &Element, &Element, Option<&Element>) -> bool
+            + 'static,
+    {
+        self.accepts(typed::accepts(accepts))
+    }
+
+    /// Copies rather than moves items dragged out of containers matching
+    /// `selector`, through [`Options::copy`](crate::Options::copy)
+    pub fn copy_from(self, selector: &str) -> Self {
+        let selector = String::from(selector);
+        self.copy(typed::copy(move |_el, source| matches(source, &selector)))
+    }
+
+    /// Sets [`Options::accepts`](crate::Options::accepts) and
+    /// [`Options::copy`](crate::Options::copy) from the rules of `groups`.
+    /// See [`Groups::apply`](crate::groups::Groups::apply).
+    pub fn groups(self, groups: &Groups) -> Self {
+        self.accepts(groups.accepts()).copy(groups.copy())
+    }
+}
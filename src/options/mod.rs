@@ -1,6 +1,12 @@
-use crate::closure;
+use crate::closure::{self, OwnedClosure};
+#[cfg(feature = "serde")]
+use crate::persistence::Persistence;
+use std::fmt;
 use wasm_bindgen::prelude::*;
 
+mod builder;
+pub use builder::OptionsBuilder;
+
 /// Since the `copy` option can be either a function or a boolean, this enum
 /// encapsulates the possible values for the copy option.
 ///
@@ -15,7 +21,7 @@ impl From<CopyValue> for JsValue {
     fn from(copy: CopyValue) -> JsValue {
         match copy {
             CopyValue::Bool(copy) => JsValue::from(copy),
-            CopyValue::Func(copy) => closure::to_js_2_ret(copy),
+            CopyValue::Func(copy) => closure::to_js_2_ret(copy).forget(),
         }
     }
 }
@@ -27,23 +33,124 @@ impl From<CopyValue> for JsValue {
 /// point where the mouse was released. If the `direction` is `Vertical`,
 /// the default value, the Y axis will be considered. Otherwise, if the
 /// `direction` is `Horizontal`, the X axis will be considered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Direction {
     Vertical,
     Horizontal,
 }
 
-impl ToString for Direction {
-    fn to_string(&self) -> String {
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         const VERTICAL: &str = "vertical";
         const HORIZONTAL: &str = "horizontal";
 
         match self {
-            Direction::Vertical => String::from(VERTICAL),
-            Direction::Horizontal => String::from(HORIZONTAL),
+            Direction::Vertical => f.write_str(VERTICAL),
+            Direction::Horizontal => f.write_str(HORIZONTAL),
+        }
+    }
+}
+
+/// Settings for scrolling containers and the viewport while dragging, as set
+/// through [`Options::autoscroll`](Options::autoscroll)
+///
+/// While an item is being dragged, any scrollable ancestor of the container
+/// it would be dropped in, as well as the viewport itself, is scrolled once
+/// the pointer comes within `margin` pixels of one of its edges. Scrolling
+/// happens along both axes, so it works the same whether the `direction` is
+/// [`Vertical`](Direction::Vertical) or [`Horizontal`](Direction::Horizontal),
+/// and stops as soon as the drag ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AutoScroll {
+    /// The distance from an edge, in pixels, at which scrolling starts
+    ///
+    /// `40` by default.
+    pub margin: u32,
+    /// The most pixels scrolled per animation frame, reached when the
+    /// pointer is right at the edge. Scrolling slows down as the pointer
+    /// moves away from the edge.
+    ///
+    /// `20` by default.
+    pub speed: u32,
+}
+
+impl Default for AutoScroll {
+    fn default() -> Self {
+        AutoScroll {
+            margin: 40,
+            speed: 20,
+        }
+    }
+}
+
+/// Settings for animating items into place, as set through
+/// [`Options::animation`](Options::animation)
+///
+/// Uses the FLIP technique: whenever the shadow of the dragged item moves,
+/// the items of the containers involved slide from their old positions to
+/// their new ones, rather than jumping. Once the item is dropped, it slides
+/// from where the mirror image was left into its final place.
+///
+/// No animation is played while the user asks for reduced motion, through
+/// the `prefers-reduced-motion` media query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Animation {
+    /// How long each animation lasts, in milliseconds
+    ///
+    /// `150` by default.
+    pub duration: u32,
+    /// The CSS easing function of each animation, such as `ease-in-out` or
+    /// `cubic-bezier(0.2, 0, 0, 1)`
+    ///
+    /// `ease-out` by default.
+    pub easing: String,
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Animation {
+            duration: 150,
+            easing: String::from("ease-out"),
         }
     }
 }
 
+/// The value of [`Options::persistence`](Options::persistence)
+///
+/// Present in every build, so that `Options` has the same fields whichever
+/// features are turned on, but only holds a
+/// [`Persistence`](crate::persistence::Persistence) when feature `serde` is
+/// turned on. Created from a `Persistence`, or an `Option` of one, with
+/// `into()`.
+///
+/// Empty by default.
+#[derive(Clone, Default)]
+pub struct PersistenceOption {
+    #[cfg(feature = "serde")]
+    persistence: Option<Persistence>,
+}
+
+#[cfg(feature = "serde")]
+impl PersistenceOption {
+    pub(crate) fn take(&mut self) -> Option<Persistence> {
+        self.persistence.take()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Option<Persistence>> for PersistenceOption {
+    fn from(persistence: Option<Persistence>) -> Self {
+        PersistenceOption { persistence }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Persistence> for PersistenceOption {
+    fn from(persistence: Persistence) -> Self {
+        PersistenceOption::from(Some(persistence))
+    }
+}
+
 /// Used to pass options when activating Dragula
 ///
 /// When passed to the [`dragula_options`](crate::dragula_options) function,
@@ -176,6 +283,33 @@ pub struct Options {
     ///
     /// `0` by default.
     pub slide_factor_y: i32,
+    /// When set, containers and the viewport are scrolled while an item is
+    /// dragged near their edges. See [`AutoScroll`](AutoScroll) for details.
+    ///
+    /// `None` by default.
+    pub autoscroll: Option<AutoScroll>,
+    /// When set, items slide into place instead of jumping as the shadow
+    /// moves and once they are dropped. See [`Animation`](Animation) for
+    /// details.
+    ///
+    /// `None` by default.
+    pub animation: Option<Animation>,
+    /// When set, the order of the items is restored as soon as the
+    /// [`Drake`](crate::Drake) is created, and saved after every `drop`. See
+    /// [`Persistence`](crate::persistence::Persistence) for details.
+    ///
+    /// Empty by default.
+    ///
+    /// Can only be set when feature `serde` is turned on
+    pub persistence: PersistenceOption,
+}
+
+impl Options {
+    /// Returns an [`OptionsBuilder`](crate::options::OptionsBuilder) starting
+    /// from the default options
+    pub fn builder() -> OptionsBuilder {
+        OptionsBuilder::default()
+    }
 }
 
 impl Default for Options {
@@ -195,6 +329,9 @@ impl Default for Options {
             ignore_input_text_selection: true,
             slide_factor_x: 0,
             slide_factor_y: 0,
+            autoscroll: None,
+            animation: None,
+            persistence: PersistenceOption::default(),
         }
     }
 }
@@ -231,14 +368,30 @@ pub struct OptionsImpl {
     pub slide_factor_y: i32,
 }
 
-impl From<Options> for OptionsImpl {
-    fn from(options: Options) -> Self {
-        OptionsImpl {
-            is_container_func: closure::to_js_1_ret(options.is_container),
-            moves_func: closure::to_js_4_ret(options.moves),
-            accepts_func: closure::to_js_4_ret(options.accepts),
-            invalid_func: closure::to_js_2_ret(options.invalid),
-            copy_func_or_bool: JsValue::from(options.copy),
+impl OptionsImpl {
+    /// Converts `options` without leaking its closures. The returned closures
+    /// must be kept alive for as long as Dragula may call them.
+    pub(crate) fn with_closures(
+        options: Options,
+    ) -> (OptionsImpl, Vec<OwnedClosure>) {
+        let is_container = closure::to_js_1_ret(options.is_container);
+        let moves = closure::to_js_4_ret(options.moves);
+        let accepts = closure::to_js_4_ret(options.accepts);
+        let invalid = closure::to_js_2_ret(options.invalid);
+        let (copy, copy_func) = match options.copy {
+            CopyValue::Bool(copy) => (JsValue::from(copy), None),
+            CopyValue::Func(copy) => {
+                let copy = closure::to_js_2_ret(copy);
+                (copy.function().clone(), Some(copy))
+            }
+        };
+
+        let options = OptionsImpl {
+            is_container_func: is_container.function().clone(),
+            moves_func: moves.function().clone(),
+            accepts_func: accepts.function().clone(),
+            invalid_func: invalid.function().clone(),
+            copy_func_or_bool: copy,
             mirror_container_elem: options.mirror_container,
             copy_sort_source: options.copy_sort_source,
             revert_on_spill: options.revert_on_spill,
@@ -247,7 +400,22 @@ impl From<Options> for OptionsImpl {
             ignore_input_text_selection: options.ignore_input_text_selection,
             slide_factor_x: options.slide_factor_x,
             slide_factor_y: options.slide_factor_y,
+        };
+
+        let mut closures = vec![is_container, moves, accepts, invalid];
+        closures.extend(copy_func);
+
+        (options, closures)
+    }
+}
+
+impl From<Options> for OptionsImpl {
+    fn from(options: Options) -> Self {
+        let (options, closures) = OptionsImpl::with_closures(options);
+        for closure in closures {
+            closure.forget();
         }
+        options
     }
 }
 
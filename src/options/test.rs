@@ -136,3 +136,163 @@ fn moves_excludes_correctly() {
 
     run_dom_test(test, &html);
 }
+
+#[wasm_bindgen_test]
+fn builder_sets_options() {
+    let options = Options::builder()
+        .copy(CopyValue::Bool(true))
+        .copy_sort_source(true)
+        .revert_on_spill(true)
+        .remove_on_spill(true)
+        .direction(Direction::Horizontal)
+        .ignore_input_text_selection(false)
+        .slide_factor_x(10)
+        .slide_factor_y(20)
+        .autoscroll(AutoScroll {
+            margin: 30,
+            speed: 5,
+        })
+        .animation(Animation {
+            duration: 200,
+            easing: String::from("linear"),
+        })
+        .build();
+    assert_eq!(
+        options.autoscroll,
+        Some(AutoScroll {
+            margin: 30,
+            speed: 5,
+        })
+    );
+    assert_eq!(
+        options.animation,
+        Some(Animation {
+            duration: 200,
+            easing: String::from("linear"),
+        })
+    );
+    let options = OptionsImpl::from(options);
+
+    assert_eq!(options.copy_func_or_bool(), JsValue::TRUE);
+    assert!(options.copy_sort_source);
+    assert!(options.revert_on_spill);
+    assert!(options.remove_on_spill);
+    assert_eq!(options.direction(), "horizontal");
+    assert!(!options.ignore_input_text_selection);
+    assert_eq!(options.slide_factor_x, 10);
+    assert_eq!(options.slide_factor_y, 20);
+}
+
+#[wasm_bindgen_test]
+fn autoscroll_off_by_default() {
+    let options = Options::default();
+
+    assert_eq!(options.autoscroll, None);
+}
+
+#[wasm_bindgen_test]
+fn animation_off_by_default() {
+    let options = Options::default();
+
+    assert_eq!(options.animation, None);
+    assert_eq!(Animation::default().easing, "ease-out");
+}
+
+#[wasm_bindgen_test]
+#[cfg(feature = "web-sys")]
+fn builder_containers_matching_includes_correctly() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 3);
+
+    let test = |element: &Element| {
+        let in_containers: Vec<JsValue> = vec![];
+        let options = Options::builder().containers_matching("#cnt_0").build();
+        let drake = dragula_options(&in_containers, options);
+
+        let containers = element.children();
+        let first = containers.item(0).unwrap().first_element_child().unwrap();
+        let second = containers.item(1).unwrap().first_element_child().unwrap();
+
+        assert!(drake.can_move(&first));
+        assert!(!drake.can_move(&second));
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+#[cfg(feature = "web-sys")]
+fn builder_handle_requires_matching_handle() {
+    console_error_panic_hook::set_once();
+
+    let html = "<div id=\"cnt\">\
+                <div class=\"grip\" id=\"with_grip\"></div>\
+                <div id=\"without_grip\"><span class=\"grip\"></span></div>\
+                </div>";
+
+    let test = |element: &Element| {
+        let in_containers = vec![element.first_element_child().unwrap()];
+        let container = &in_containers[0];
+        let options = Options::builder().handle(".grip").build();
+        let drake = dragula_options(&in_containers, options);
+
+        // `can_move` uses the item itself as the handle
+        let with_grip = container.first_element_child().unwrap();
+        let without_grip = container.last_element_child().unwrap();
+
+        assert!(drake.can_move(&with_grip));
+        assert!(!drake.can_move(&without_grip));
+    };
+
+    run_dom_test(test, html);
+}
+
+#[wasm_bindgen_test]
+#[cfg(feature = "web-sys")]
+fn builder_invalid_selector_excludes_correctly() {
+    console_error_panic_hook::set_once();
+
+    let html = "<div id=\"cnt\"><a href=\"#\"></a><div></div></div>";
+
+    let test = |element: &Element| {
+        let in_containers = vec![element.first_element_child().unwrap()];
+        let container = &in_containers[0];
+        let options = Options::builder().invalid_selector("input, a").build();
+        let drake = dragula_options(&in_containers, options);
+
+        let link = container.first_element_child().unwrap();
+        let div = container.last_element_child().unwrap();
+
+        assert!(!drake.can_move(&link));
+        assert!(drake.can_move(&div));
+    };
+
+    run_dom_test(test, html);
+}
+
+#[wasm_bindgen_test]
+#[cfg(feature = "web-sys")]
+fn builder_copy_from_matches_source() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 1);
+
+    let test = |element: &Element| {
+        let options = Options::builder().copy_from("#cnt_0").build();
+        let mut copy = match options.copy {
+            CopyValue::Func(copy) => copy,
+            CopyValue::Bool(_) => panic!("copy_from should set a closure"),
+        };
+
+        let containers = element.children();
+        let palette = containers.item(0).unwrap();
+        let lane = containers.item(1).unwrap();
+        let item = palette.first_element_child().unwrap();
+
+        assert!(copy(item.clone().into(), palette.into()));
+        assert!(!copy(item.into(), lane.into()));
+    };
+
+    run_dom_test(test, &html);
+}
@@ -0,0 +1,230 @@
+//! Saving and restoring the order of items across page loads
+//!
+//! Requires that feature `serde` be turned on.
+//!
+//! A [`Persistence`](Persistence) set as
+//! [`Options::persistence`](crate::Options::persistence) restores the order
+//! of the items of a [`Drake`](crate::Drake) as soon as it is created by
+//! [`dragula_options`](crate::dragula_options) or
+//! [`dragula_owned`](crate::dragula_owned), and saves it again after every
+//! `drop`. Containers and items are identified by the value of an attribute
+//! on each of them, as with
+//! [`Drake::serialize_order`](crate::Drake::serialize_order).
+//!
+//! The order is saved as JSON to a [`Storage`](Storage), which is
+//! `localStorage` when using [`Persistence::local`](Persistence::local).
+//! Other backends, such as `sessionStorage` through
+//! [`WebStorage`](WebStorage) or the in-memory
+//! [`MemoryStorage`](MemoryStorage), can be passed to
+//! [`Persistence::new`](Persistence::new).
+//!
+//! Saved ids that no longer match a container or item are ignored when
+//! restoring, and items that were not saved are placed after those that
+//! were, so that a saved order survives items being added or deleted. Items
+//! sharing an id, such as copies, are only restored to the first place it
+//! was saved in; the others are treated as if they were not saved.
+//!
+//! ```no_run
+//! use dragula::*;
+//! use dragula::persistence::Persistence;
+//!
+//! let doc = web_sys::window().unwrap().document().unwrap();
+//! let todo = doc.get_element_by_id("todo").unwrap();
+//! let done = doc.get_element_by_id("done").unwrap();
+//!
+//! let options = Options {
+//!     persistence: Persistence::local("board", "data-id").into(),
+//!     ..Options::default()
+//! };
+//!
+//! // Items are put back in the order they were saved in
+//! let drake = dragula_options(&[todo, done], options);
+//!
+//! //--snip--
+//! ```
+use crate::{Drake, Layout, ListenerHandle};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+/// Where a [`Persistence`](Persistence) saves the order of items
+///
+/// Modelled after the Web Storage API, so that `localStorage` and
+/// `sessionStorage` can be used through [`WebStorage`](WebStorage).
+/// Asynchronous stores, such as IndexedDB, can be used by keeping a copy of
+/// their contents in memory.
+pub trait Storage {
+    /// Returns the value saved under `key`, if any
+    fn get(&self, key: &str) -> Option<String>;
+
+    /// Saves `value` under `key`, replacing what was saved there before
+    fn set(&self, key: &str, value: &str);
+}
+
+/// A [`Storage`](Storage) backed by `localStorage` or `sessionStorage`
+pub struct WebStorage {
+    storage: web_sys::Storage,
+}
+
+impl WebStorage {
+    /// Returns `localStorage`, if the page has access to it
+    pub fn local() -> Option<Self> {
+        let storage = web_sys::window()?.local_storage().ok()??;
+        Some(WebStorage { storage })
+    }
+
+    /// Returns `sessionStorage`, if the page has access to it
+    pub fn session() -> Option<Self> {
+        let storage = web_sys::window()?.session_storage().ok()??;
+        Some(WebStorage { storage })
+    }
+}
+
+impl From<web_sys::Storage> for WebStorage {
+    fn from(storage: web_sys::Storage) -> Self {
+        WebStorage { storage }
+    }
+}
+
+impl Storage for WebStorage {
+    fn get(&self, key: &str) -> Option<String> {
+        self.storage.get_item(key).ok().flatten()
+    }
+
+    fn set(&self, key: &str, value: &str) {
+        // Saving fails when the storage is full or disabled, in which case
+        // the order is simply not kept
+        let _ = self.storage.set_item(key, value);
+    }
+}
+
+/// A [`Storage`](Storage) that only lives in memory
+///
+/// Clones share the same contents.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStorage {
+    values: Rc<RefCell<HashMap<String, String>>>,
+}
+
+impl MemoryStorage {
+    /// Creates an empty storage
+    pub fn new() -> Self {
+        MemoryStorage::default()
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn get(&self, key: &str) -> Option<String> {
+        self.values.borrow().get(key).cloned()
+    }
+
+    fn set(&self, key: &str, value: &str) {
+        self.values
+            .borrow_mut()
+            .insert(String::from(key), String::from(value));
+    }
+}
+
+/// Keeps the `saved` ids that are still found in `current`, listing each item
+/// only once
+fn retain_known(saved: Layout, current: &Layout) -> Layout {
+    let containers: HashSet<_> =
+        current.containers.iter().map(|(id, _)| id).collect();
+    let items: HashSet<_> = current
+        .containers
+        .iter()
+        .flat_map(|(_, items)| items)
+        .collect();
+    let mut seen = HashSet::new();
+
+    Layout {
+        attribute: current.attribute.clone(),
+        containers: saved
+            .containers
+            .into_iter()
+            .filter(|(id, _)| containers.contains(id))
+            .map(|(id, mut ids)| {
+                ids.retain(|item| {
+                    items.contains(item) && seen.insert(item.clone())
+                });
+                (id, ids)
+            })
+            .collect(),
+    }
+}
+
+/// Saves the order of the items of a [`Drake`](crate::Drake) under a key
+/// of a [`Storage`](Storage)
+///
+/// See the [module documentation](self) for details.
+#[derive(Clone)]
+pub struct Persistence {
+    key: String,
+    attribute: String,
+    storage: Rc<dyn Storage>,
+}
+
+impl Persistence {
+    /// Saves the order under `key` of `storage`, identifying containers and
+    /// items by the value of `attribute`
+    pub fn new<S>(key: &str, attribute: &str, storage: S) -> Self
+    where
+        S: Storage + 'static,
+    {
+        Persistence {
+            key: String::from(key),
+            attribute: String::from(attribute),
+            storage: Rc::new(storage),
+        }
+    }
+
+    /// Saves the order under `key` of `localStorage`, identifying containers
+    /// and items by the value of `attribute`. Returns `None` if the page has
+    /// no access to `localStorage`.
+    pub fn local(key: &str, attribute: &str) -> Option<Self> {
+        let storage = WebStorage::local()?;
+        Some(Persistence::new(key, attribute, storage))
+    }
+
+    /// Saves the current order of the items of `drake`. Nothing is saved if
+    /// a container or item lacks the attribute.
+    pub fn save(&self, drake: &Drake) {
+        if let Ok(layout) = drake.serialize_order(&self.attribute) {
+            if let Ok(json) = serde_json::to_string(&layout) {
+                self.storage.set(&self.key, &json);
+            }
+        }
+    }
+
+    /// Puts the items of `drake` back in the saved order, returning whether
+    /// an order was saved. Saved ids that are no longer found are ignored.
+    pub fn restore(&self, drake: &Drake) -> bool {
+        let saved = self
+            .storage
+            .get(&self.key)
+            .and_then(|json| serde_json::from_str::<Layout>(&json).ok());
+        let (saved, current) =
+            match (saved, drake.serialize_order(&self.attribute)) {
+                (Some(saved), Ok(current)) => (saved, current),
+                _ => return false,
+            };
+
+        // Only known ids are left, each listed once, so restoring cannot
+        // fail
+        drake.restore_order(&retain_known(saved, &current)).is_ok()
+    }
+
+    /// Restores the saved order of `drake`, and saves it again after every
+    /// `drop`, until the returned handle is dropped
+    pub(crate) fn attach(self, drake: &Drake) -> ListenerHandle {
+        self.restore(drake);
+
+        let d = drake.clone();
+        drake
+            .clone()
+            .on_drop(move |_el, _target, _source, _sibling| self.save(&d))
+    }
+}
+
+#[cfg(test)]
+mod test;
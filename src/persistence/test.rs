@@ -0,0 +1,130 @@
+use super::*;
+use crate::test_utils::*;
+use crate::{dragula_options, dragula_owned, Options};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use web_sys::Element;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn options(storage: &MemoryStorage) -> Options {
+    Options {
+        persistence: Persistence::new("board", "data-id", storage.clone())
+            .into(),
+        ..Options::default()
+    }
+}
+
+#[wasm_bindgen_test]
+fn saves_after_drop_and_restores_on_creation() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 2);
+    let storage = MemoryStorage::new();
+
+    let test = |element: &Element| {
+        let containers = with_ids(element);
+        let drake = dragula_options(&containers, options(&storage));
+        assert_eq!(storage.get("board"), None);
+
+        let item = containers[0].first_element_child().unwrap();
+        containers[1].append_child(&item).unwrap();
+        drake.emit_4(
+            "drop",
+            &item.clone().into(),
+            &containers[1].clone().into(),
+            &containers[0].clone().into(),
+            &JsValue::NULL,
+        );
+        assert!(storage.get("board").is_some());
+        drake.destroy();
+    };
+    run_dom_test(test, &html);
+
+    let test = |element: &Element| {
+        let containers = with_ids(element);
+        let _drake = dragula_owned(&containers, options(&storage));
+
+        assert_eq!(ids(&containers[0]), ["drag_0_1"]);
+        assert_eq!(ids(&containers[1]), ["drag_1_0", "drag_1_1", "drag_0_0"]);
+    };
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn restore_ignores_unknown_ids() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 2);
+    let storage = MemoryStorage::new();
+    storage.set(
+        "board",
+        r#"{"attribute":"data-id","containers":[
+            ["cnt_0",["drag_9_9","drag_0_1","drag_0_0"]],
+            ["cnt_9",["drag_1_0"]]
+        ]}"#,
+    );
+
+    let test = |element: &Element| {
+        let containers = with_ids(element);
+        let _drake = dragula_owned(&containers, options(&storage));
+
+        assert_eq!(ids(&containers[0]), ["drag_0_1", "drag_0_0"]);
+        assert_eq!(ids(&containers[1]), ["drag_1_0", "drag_1_1"]);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn restore_without_saved_order_changes_nothing() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(1, 2);
+    let storage = MemoryStorage::new();
+    storage.set("board", "not json");
+
+    let test = |element: &Element| {
+        let containers = with_ids(element);
+        let drake = crate::dragula(&containers);
+        let persistence = Persistence::new("board", "data-id", storage.clone());
+
+        assert!(!persistence.restore(&drake));
+        assert_eq!(ids(&containers[0]), ["drag_0_0", "drag_0_1"]);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn restore_places_shared_ids_once() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 2);
+    let storage = MemoryStorage::new();
+    storage.set(
+        "board",
+        r#"{"attribute":"data-id","containers":[
+            ["cnt_0",["drag_0_1","drag_0_0"]],
+            ["cnt_1",["drag_0_0","drag_1_1","drag_1_0"]]
+        ]}"#,
+    );
+
+    let test = |element: &Element| {
+        let containers = with_ids(element);
+        // A copy keeps the attributes of the item it was copied from
+        let item = containers[0].first_element_child().unwrap();
+        let copy = item.clone_node_with_deep(true).unwrap();
+        containers[1].append_child(&copy).unwrap();
+
+        let drake = crate::dragula(&containers);
+        let persistence = Persistence::new("board", "data-id", storage.clone());
+
+        assert!(persistence.restore(&drake));
+        assert_eq!(ids(&containers[0]), ["drag_0_1", "drag_0_0"]);
+        assert_eq!(ids(&containers[1]), ["drag_1_1", "drag_1_0", "drag_0_0"]);
+        assert_eq!(containers[0].last_element_child(), Some(item));
+    };
+
+    run_dom_test(test, &html);
+}
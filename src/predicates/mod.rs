@@ -0,0 +1,223 @@
+//! Reusable predicates for [`Options`](crate::Options) closures
+//!
+//! Requires that feature `web-sys` be turned on.
+//!
+//! A [`Predicate`](Predicate) checks the elements that Dragula passes to
+//! [`moves`](crate::Options::moves), [`accepts`](crate::Options::accepts),
+//! [`invalid`](crate::Options::invalid), and [`copy`](crate::Options::copy).
+//! Predicates are combined with [`and`](Predicate::and) and
+//! [`or`](Predicate::or), negated with `!` or `not`, and then turned into the
+//! closure type expected by the option they are meant for, with
+//! [`into_moves`](Predicate::into_moves),
+//! [`into_accepts`](Predicate::into_accepts),
+//! [`into_invalid`](Predicate::into_invalid), or
+//! [`into_copy`](Predicate::into_copy).
+//!
+//! ```no_run
+//! use dragula::*;
+//! use dragula::predicates::*;
+//!
+//! let doc = web_sys::window().unwrap().document().unwrap();
+//! let element = doc.get_element_by_id("drag-container").unwrap();
+//! let trash = doc.get_element_by_id("trash").unwrap();
+//!
+//! let options = Options {
+//!     moves: within(".grip").and(!has_class("locked")).into_moves(),
+//!     accepts: same_container()
+//!         .or(target_is(&trash))
+//!         .or(data_attr_eq("kind", "task").and(matches(".card")))
+//!         .into_accepts(),
+//!     invalid: within("input, a").into_invalid(),
+//!     ..Options::default()
+//! };
+//!
+//! let drake = dragula_options(&[element], options);
+//!
+//! //--snip--
+//! ```
+use crate::options::CopyValue;
+use crate::typed;
+use std::ops::Not;
+use std::rc::Rc;
+use wasm_bindgen::JsValue;
+use web_sys::Element;
+
+/// The elements passed by Dragula to the closure a
+/// [`Predicate`](Predicate) was turned into
+///
+/// Elements that the closure is not given, such as `target` for
+/// [`Options::moves`](crate::Options::moves), are `None`, as is a `sibling`
+/// that Dragula passes as `null`.
+#[derive(Debug, Clone, Copy)]
+pub struct Args<'a> {
+    /// The item being checked
+    pub el: &'a Element,
+    /// The container the item comes from
+    pub source: Option<&'a Element>,
+    /// The container the item would be dropped in
+    pub target: Option<&'a Element>,
+    /// The element that was clicked on
+    pub handle: Option<&'a Element>,
+    /// The element the item would be placed before
+    pub sibling: Option<&'a Element>,
+}
+
+/// A check on the elements passed to an [`Options`](crate::Options) closure
+///
+/// See the [module documentation](self) for details. Cloning a predicate is
+/// cheap, so that the same one can be used in several options.
+#[derive(Clone)]
+pub struct Predicate {
+    check: Rc<dyn Fn(&Args) -> bool>,
+}
+
+impl Predicate {
+    /// Creates a predicate from a closure taking [`Args`](Args)
+    pub fn new<F>(check: F) -> Self
+    where
+        F: Fn(&Args) -> bool + 'static,
+    {
+        Predicate {
+            check: Rc::new(check),
+        }
+    }
+
+    /// Runs the predicate against `args`
+    pub fn check(&self, args: &Args) -> bool {
+        (self.check)(args)
+    }
+
+    /// Holds when both this predicate and `other` hold. `other` is only run
+    /// if this predicate holds.
+    pub fn and(self, other: Predicate) -> Self {
+        Predicate::new(move |args| self.check(args) && other.check(args))
+    }
+
+    /// Holds when either this predicate or `other` holds. `other` is only
+    /// run if this predicate does not hold.
+    pub fn or(self, other: Predicate) -> Self {
+        Predicate::new(move |args| self.check(args) || other.check(args))
+    }
+
+    /// Turns the predicate into a closure for
+    /// [`Options::moves`](crate::Options::moves)
+    pub fn into_moves(
+        self,
+    ) -> Box<dyn FnMut(JsValue, JsValue, JsValue, JsValue) -> bool> {
+        typed::moves(move |el, source, handle, sibling| {
+            self.check(&Args {
+                el,
+                source: Some(source),
+                target: None,
+                handle: Some(handle),
+                sibling,
+            })
+        })
+    }
+
+    /// Turns the predicate into a closure for
+    /// [`Options::accepts`](crate::Options::accepts)
+    pub fn into_accepts(
+        self,
+    ) -> Box<dyn FnMut(JsValue, JsValue, JsValue, JsValue) -> bool> {
+        typed::accepts(move |el, target, source, sibling| {
+            self.check(&Args {
+                el,
+                source: Some(source),
+                target: Some(target),
+                handle: None,
+                sibling,
+            })
+        })
+    }
+
+    /// Turns the predicate into a closure for
+    /// [`Options::invalid`](crate::Options::invalid)
+    pub fn into_invalid(self) -> Box<dyn FnMut(JsValue, JsValue) -> bool> {
+        typed::invalid(move |el, handle| {
+            self.check(&Args {
+                el,
+                source: None,
+                target: None,
+                handle: Some(handle),
+                sibling: None,
+            })
+        })
+    }
+
+    /// Turns the predicate into a
+    /// [`CopyValue::Func`](crate::options::CopyValue::Func) for
+    /// [`Options::copy`](crate::Options::copy)
+    pub fn into_copy(self) -> CopyValue {
+        typed::copy(move |el, source| {
+            self.check(&Args {
+                el,
+                source: Some(source),
+                target: None,
+                handle: None,
+                sibling: None,
+            })
+        })
+    }
+}
+
+/// Holds when the predicate does not
+impl Not for Predicate {
+    type Output = Predicate;
+
+    fn not(self) -> Predicate {
+        Predicate::new(move |args| !self.check(args))
+    }
+}
+
+/// Holds when the item has the class `class`
+pub fn has_class(class: &str) -> Predicate {
+    let class = String::from(class);
+    Predicate::new(move |args| args.el.class_list().contains(&class))
+}
+
+/// Holds when the item matches `selector`. Selectors that fail to parse
+/// never match.
+pub fn matches(selector: &str) -> Predicate {
+    let selector = String::from(selector);
+    Predicate::new(move |args| args.el.matches(&selector).unwrap_or(false))
+}
+
+/// Holds when the element that was clicked on, or one of its ancestors,
+/// matches `selector`. The item itself is checked when there is no clicked
+/// element, as for [`Options::accepts`](crate::Options::accepts). Selectors
+/// that fail to parse never match.
+pub fn within(selector: &str) -> Predicate {
+    let selector = String::from(selector);
+    Predicate::new(move |args| {
+        let el = args.handle.unwrap_or(args.el);
+        matches!(el.closest(&selector), Ok(Some(_)))
+    })
+}
+
+/// Holds when the item would be dropped back in the container it comes
+/// from. Never holds when either container is unknown.
+pub fn same_container() -> Predicate {
+    Predicate::new(|args| match (args.target, args.source) {
+        (Some(target), Some(source)) => target == source,
+        _ => false,
+    })
+}
+
+/// Holds when the item would be dropped in `container`
+pub fn target_is(container: &Element) -> Predicate {
+    let container = container.clone();
+    Predicate::new(move |args| args.target == Some(&container))
+}
+
+/// Holds when the `data-{name}` attribute of the item equals `value`
+pub fn data_attr_eq(name: &str, value: &str) -> Predicate {
+    let attribute = format!("data-{}", name);
+    let value = String::from(value);
+    Predicate::new(move |args| {
+        args.el.get_attribute(&attribute).as_deref() == Some(value.as_str())
+    })
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,157 @@
+use super::*;
+use crate::test_utils::*;
+use crate::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn item(el: &Element) -> Args<'_> {
+    Args {
+        el,
+        source: None,
+        target: None,
+        handle: None,
+        sibling: None,
+    }
+}
+
+#[wasm_bindgen_test]
+fn element_predicates_check_item() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(1, 2);
+
+    let test = |element: &Element| {
+        let container = &containers(element)[0];
+        let first = container.first_element_child().unwrap();
+        let last = container.last_element_child().unwrap();
+        first.class_list().add_1("locked").unwrap();
+        first.set_attribute("data-kind", "task").unwrap();
+
+        assert!(has_class("locked").check(&item(&first)));
+        assert!(!has_class("locked").check(&item(&last)));
+        assert!(matches("#drag_0_1").check(&item(&last)));
+        assert!(!matches("[invalid").check(&item(&last)));
+        assert!(data_attr_eq("kind", "task").check(&item(&first)));
+        assert!(!data_attr_eq("kind", "task").check(&item(&last)));
+        assert!(within("#cnt_0").check(&item(&last)));
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn predicates_compose() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(1, 1);
+
+    let test = |element: &Element| {
+        let container = &containers(element)[0];
+        let el = container.first_element_child().unwrap();
+        let args = item(&el);
+
+        let yes = matches("div");
+        let no = has_class("missing");
+
+        assert!(yes.clone().and(!no.clone()).check(&args));
+        assert!(!yes.clone().and(no.clone()).check(&args));
+        assert!(no.clone().or(yes.clone()).check(&args));
+        assert!(!no.clone().or(!yes).check(&args));
+        assert!(no.not().check(&args));
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn into_accepts_checks_containers() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(3, 1);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let el = containers[0].first_element_child().unwrap();
+        let options = Options {
+            accepts: same_container()
+                .or(target_is(&containers[2]))
+                .into_accepts(),
+            ..Options::default()
+        };
+        let mut accepts = options.accepts;
+
+        let mut check = |target: &Element| {
+            accepts(
+                el.clone().into(),
+                target.into(),
+                containers[0].clone().into(),
+                JsValue::NULL,
+            )
+        };
+
+        assert!(check(&containers[0]));
+        assert!(!check(&containers[1]));
+        assert!(check(&containers[2]));
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn into_moves_and_invalid_check_handle() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 1);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let el = containers[0].first_element_child().unwrap();
+        let other = containers[1].first_element_child().unwrap();
+
+        let mut moves = within("#drag_0_0").into_moves();
+        assert!(moves(
+            el.clone().into(),
+            containers[0].clone().into(),
+            el.clone().into(),
+            JsValue::NULL,
+        ));
+        assert!(!moves(
+            el.clone().into(),
+            containers[0].clone().into(),
+            other.clone().into(),
+            JsValue::NULL,
+        ));
+
+        let mut invalid = within("#cnt_1").into_invalid();
+        assert!(invalid(el.clone().into(), other.into()));
+        assert!(!invalid(el.clone().into(), el.into()));
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn into_copy_checks_source() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 1);
+
+    let test = |element: &Element| {
+        let containers = containers(element);
+        let el = containers[0].first_element_child().unwrap();
+        let palette = containers[0].clone();
+
+        let source_is_palette =
+            Predicate::new(move |args| args.source == Some(&palette));
+        let mut copy = match source_is_palette.into_copy() {
+            CopyValue::Func(copy) => copy,
+            CopyValue::Bool(_) => panic!("into_copy should set a closure"),
+        };
+
+        assert!(copy(el.clone().into(), containers[0].clone().into()));
+        assert!(!copy(el.into(), containers[1].clone().into()));
+    };
+
+    run_dom_test(test, &html);
+}
@@ -0,0 +1,300 @@
+//! Keeps Rust models in sync with the order of draggable items
+//!
+//! Requires that feature `web-sys` be turned on.
+//!
+//! A [`SortableList`](SortableList) binds each container to a shared
+//! `Vec<T>`. Every item element carries a key in one of its attributes, such
+//! as `data-id`, which identifies the model entry it renders. Whenever an
+//! item is dropped or removed, the bound models are updated to match the
+//! new order of the DOM.
+//!
+//! Copies follow [`Options::copy`](crate::Options::copy): a copied item is
+//! cloned into the target model, and the source model is left untouched.
+//!
+//! Entries taken out of a model by a `remove` are kept aside, so that they
+//! can be put back if the item is dropped again, as when a
+//! [`History`](crate::history::History) undoes the removal. Only the most
+//! recent ones are kept, up to a [depth](SortableListBuilder::removed_depth)
+//! that should match the depth of the `History`.
+//!
+//! ```no_run
+//! use dragula::*;
+//! use dragula::sortable::SortableList;
+//! use std::cell::RefCell;
+//! use std::rc::Rc;
+//!
+//! #[derive(Clone)]
+//! struct Task {
+//!     id: u32,
+//!     title: String,
+//! }
+//!
+//! let doc = web_sys::window().unwrap().document().unwrap();
+//! let todo_container = doc.get_element_by_id("todo").unwrap();
+//! let done_container = doc.get_element_by_id("done").unwrap();
+//!
+//! let todo: Rc<RefCell<Vec<Task>>> = Rc::new(RefCell::new(Vec::new()));
+//! let done: Rc<RefCell<Vec<Task>>> = Rc::new(RefCell::new(Vec::new()));
+//!
+//! let key = |task: &Task| task.id.to_string();
+//! let list = SortableList::builder("data-id", key)
+//!     .bind(&todo_container, todo.clone())
+//!     .bind(&done_container, done.clone())
+//!     .on_change(|container, tasks| {
+//!         // `tasks` is the new contents of `container`
+//!     })
+//!     .build(Options::default());
+//!
+//! //--snip--
+//! ```
+use crate::drake::{index_where, DragState};
+use crate::event::*;
+use crate::history::DEFAULT_DEPTH;
+use crate::{dragula_owned, Drake, Options, OwnedDrake};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use web_sys::Element;
+
+/// The model bound to a single container
+pub type Model<T> = Rc<RefCell<Vec<T>>>;
+
+type OnChange<T> = Box<dyn FnMut(&Element, &[T])>;
+
+struct Binding<T> {
+    container: Element,
+    model: Model<T>,
+}
+
+struct ModelSync<T> {
+    attribute: String,
+    key: Box<dyn Fn(&T) -> String>,
+    bindings: Vec<Binding<T>>,
+    on_change: OnChange<T>,
+    drag: DragState,
+    /// Entries taken out by a `remove`, in case the item is dropped again
+    removed: VecDeque<T>,
+    removed_depth: usize,
+}
+
+impl<T> ModelSync<T>
+where
+    T: Clone,
+{
+    fn handle(&mut self, event: DragulaEvent<Element>) {
+        self.drag.track(&event);
+
+        match event {
+            DragulaEvent::Drop(DropEvent {
+                el, target, source, ..
+            }) => self.dropped(&el, &target, &source),
+            DragulaEvent::Remove(RemoveEvent { el, source, .. }) => {
+                self.removed(&el, &source)
+            }
+            _ => {}
+        }
+    }
+
+    fn binding(&self, container: &Element) -> Option<usize> {
+        self.bindings
+            .iter()
+            .position(|binding| binding.container == *container)
+    }
+
+    fn position(&self, binding: usize, key: &str) -> Option<usize> {
+        self.bindings[binding]
+            .model
+            .borrow()
+            .iter()
+            .position(|item| (self.key)(item) == key)
+    }
+
+    fn dropped(&mut self, el: &Element, target: &Element, source: &Element) {
+        let key = match el.get_attribute(&self.attribute) {
+            Some(key) => key,
+            None => return,
+        };
+        let (source, target) =
+            match (self.binding(source), self.binding(target)) {
+                (Some(source), Some(target)) => (source, target),
+                _ => return,
+            };
+        let position = self.position(source, &key);
+        let copying = self.drag.copying();
+
+        // Copying within the source container only reorders it, since
+        // Dragula removes the original in that case
+        let item = match position {
+            Some(position) if copying && source != target => {
+                self.bindings[source].model.borrow()[position].clone()
+            }
+            Some(position) => {
+                self.bindings[source].model.borrow_mut().remove(position)
+            }
+            None => {
+                let removed = self
+                    .removed
+                    .iter()
+                    .rposition(|item| (self.key)(item) == key);
+                match removed {
+                    Some(removed) => self.removed.remove(removed).unwrap(),
+                    None => return,
+                }
+            }
+        };
+
+        {
+            let mut model = self.bindings[target].model.borrow_mut();
+            let keyed = |el: &Element| el.has_attribute(&self.attribute);
+            let index = index_where(el, keyed).min(model.len());
+            model.insert(index, item);
+        }
+
+        self.changed(target);
+        if source != target && !copying && position.is_some() {
+            self.changed(source);
+        }
+    }
+
+    fn removed(&mut self, el: &Element, source: &Element) {
+        let key = match el.get_attribute(&self.attribute) {
+            Some(key) => key,
+            None => return,
+        };
+        let source = match self.binding(source) {
+            Some(source) => source,
+            None => return,
+        };
+
+        if let Some(position) = self.position(source, &key) {
+            let item =
+                self.bindings[source].model.borrow_mut().remove(position);
+            if self.removed_depth > 0 {
+                if self.removed.len() == self.removed_depth {
+                    self.removed.pop_front();
+                }
+                self.removed.push_back(item);
+            }
+            self.changed(source);
+        }
+    }
+
+    fn changed(&mut self, binding: usize) {
+        let binding = &self.bindings[binding];
+        (self.on_change)(&binding.container, &binding.model.borrow());
+    }
+}
+
+/// Builds a [`SortableList`](SortableList)
+///
+/// Created with [`SortableList::builder`](SortableList::builder).
+pub struct SortableListBuilder<T> {
+    sync: ModelSync<T>,
+}
+
+impl<T> SortableListBuilder<T>
+where
+    T: Clone + 'static,
+{
+    /// Makes `container` draggable, keeping `model` in sync with its items.
+    /// `model` should hold one entry per keyed item, in the same order.
+    pub fn bind(mut self, container: &Element, model: Model<T>) -> Self {
+        self.sync.bindings.push(Binding {
+            container: container.clone(),
+            model,
+        });
+        self
+    }
+
+    /// Sets a callback invoked with a container and the new contents of its
+    /// model, whenever that model changes. The model is borrowed while the
+    /// callback runs, so it must not be mutably borrowed from inside it.
+    pub fn on_change<F>(mut self, on_change: F) -> Self
+    where
+        F: FnMut(&Element, &[T]) + 'static,
+    {
+        self.sync.on_change = Box::new(on_change);
+        self
+    }
+
+    /// Sets how many entries taken out by a `remove` are kept aside to be
+    /// put back, which should match the depth of the
+    /// [`History`](crate::history::History) recording the drake. Defaults to
+    /// [`DEFAULT_DEPTH`](crate::history::DEFAULT_DEPTH).
+    pub fn removed_depth(mut self, depth: usize) -> Self {
+        self.sync.removed_depth = depth;
+        self
+    }
+
+    /// Activates dragula for the bound containers with the provided options
+    pub fn build(self, options: Options) -> SortableList<T> {
+        let mut sync = self.sync;
+        let containers: Vec<_> = sync
+            .bindings
+            .iter()
+            .map(|binding| binding.container.clone())
+            .collect();
+        let models = sync
+            .bindings
+            .iter()
+            .map(|binding| binding.model.clone())
+            .collect();
+
+        let mut drake = dragula_owned(&containers, options);
+        let listener = drake.on_element_event(move |event| sync.handle(event));
+        drake.keep(listener);
+
+        SortableList { drake, models }
+    }
+}
+
+/// Draggable containers whose order is mirrored by Rust models
+///
+/// See the [module documentation](crate::sortable) for an example. The drake
+/// is destroyed once the `SortableList` is dropped.
+pub struct SortableList<T> {
+    drake: OwnedDrake,
+    models: Vec<Model<T>>,
+}
+
+impl<T> SortableList<T>
+where
+    T: Clone + 'static,
+{
+    /// Starts building a `SortableList` whose items are identified by the
+    /// value of `attribute`, matched against `key` for each model entry
+    pub fn builder<F>(attribute: &str, key: F) -> SortableListBuilder<T>
+    where
+        F: Fn(&T) -> String + 'static,
+    {
+        SortableListBuilder {
+            sync: ModelSync {
+                attribute: String::from(attribute),
+                key: Box::new(key),
+                bindings: Vec::new(),
+                on_change: Box::new(|_, _| {}),
+                drag: DragState::default(),
+                removed: VecDeque::new(),
+                removed_depth: DEFAULT_DEPTH,
+            },
+        }
+    }
+
+    /// The models bound to each container, in the order they were bound
+    pub fn models(&self) -> &[Model<T>] {
+        &self.models
+    }
+
+    /// The [`Drake`](crate::Drake) managing the bound containers
+    pub fn drake(&self) -> &Drake {
+        &self.drake
+    }
+
+    /// The [`Drake`](crate::Drake) managing the bound containers
+    pub fn drake_mut(&mut self) -> &mut Drake {
+        &mut self.drake
+    }
+}
+
+#[cfg(test)]
+mod test;
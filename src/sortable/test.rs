@@ -0,0 +1,185 @@
+use super::*;
+use crate::options::CopyValue;
+use crate::test_utils::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn models(containers: u32, items: u32) -> Vec<Model<String>> {
+    (0..containers)
+        .map(|i| {
+            let items = (0..items).map(|j| format!("{}_{}", i, j)).collect();
+            Rc::new(RefCell::new(items))
+        })
+        .collect()
+}
+
+fn sortable_list(
+    element: &Element,
+    models: &[Model<String>],
+    options: Options,
+) -> SortableList<String> {
+    let containers = element.children();
+    let builder = models.iter().enumerate().fold(
+        SortableList::builder("data-id", String::clone),
+        |builder, (i, model)| {
+            let container = containers.item(i as u32).unwrap();
+            builder.bind(&container, model.clone())
+        },
+    );
+    builder.build(options)
+}
+
+/// Drags `item` into `target` before `sibling`, then drops it
+fn drag_into(
+    drake: &mut Drake,
+    item: &Element,
+    target: &Element,
+    sibling: Option<&Element>,
+) {
+    drake.start(item);
+    target
+        .insert_before(item, sibling.map(|s| s.as_ref()))
+        .unwrap();
+    drake.end();
+}
+
+#[wasm_bindgen_test]
+fn drop_moves_between_models() {
+    console_error_panic_hook::set_once();
+
+    let html = keyed_containers(2, 3);
+
+    let test = |element: &Element| {
+        let models = models(2, 3);
+        let mut list = sortable_list(element, &models, Options::default());
+
+        let source = element.first_element_child().unwrap();
+        let target = element.last_element_child().unwrap();
+        let item = source.first_element_child().unwrap();
+        let sibling = target.children().item(1).unwrap();
+
+        drag_into(list.drake_mut(), &item, &target, Some(&sibling));
+
+        assert_eq!(*models[0].borrow(), vec!["0_1", "0_2"]);
+        assert_eq!(*models[1].borrow(), vec!["1_0", "0_0", "1_1", "1_2"]);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn drop_reorders_within_model() {
+    console_error_panic_hook::set_once();
+
+    let html = keyed_containers(1, 3);
+
+    let test = |element: &Element| {
+        let models = models(1, 3);
+        let mut list = sortable_list(element, &models, Options::default());
+
+        let container = element.first_element_child().unwrap();
+        let item = container.first_element_child().unwrap();
+
+        drag_into(list.drake_mut(), &item, &container, None);
+
+        assert_eq!(*models[0].borrow(), vec!["0_1", "0_2", "0_0"]);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn drop_copies_into_model() {
+    console_error_panic_hook::set_once();
+
+    let html = keyed_containers(2, 2);
+
+    let test = |element: &Element| {
+        let models = models(2, 2);
+        let options = Options {
+            copy: CopyValue::Bool(true),
+            ..Options::default()
+        };
+        let mut list = sortable_list(element, &models, options);
+
+        let source = element.first_element_child().unwrap();
+        let target = element.last_element_child().unwrap();
+        let item = source.first_element_child().unwrap();
+
+        let copy = Rc::new(RefCell::new(None));
+        let copy_ref = copy.clone();
+        let _listener = list.drake_mut().on_cloned(move |clone, _, _| {
+            *copy_ref.borrow_mut() = Some(Element::from(clone));
+        });
+
+        list.drake_mut().start(&item);
+        let copy = copy.borrow_mut().take().expect("Item was not copied");
+        target.append_child(&copy).unwrap();
+        list.drake_mut().end();
+
+        assert_eq!(*models[0].borrow(), vec!["0_0", "0_1"]);
+        assert_eq!(*models[1].borrow(), vec!["1_0", "1_1", "0_0"]);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn remove_removes_from_model() {
+    console_error_panic_hook::set_once();
+
+    let html = keyed_containers(1, 3);
+
+    let test = |element: &Element| {
+        let models = models(1, 3);
+        let mut list = sortable_list(element, &models, Options::default());
+
+        let container = element.first_element_child().unwrap();
+        let item = container.children().item(1).unwrap();
+
+        list.drake_mut().start(&item);
+        list.drake_mut().remove();
+
+        assert_eq!(*models[0].borrow(), vec!["0_0", "0_2"]);
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn on_change_receives_changed_models() {
+    console_error_panic_hook::set_once();
+
+    let html = keyed_containers(2, 1);
+
+    let test = |element: &Element| {
+        let models = models(2, 1);
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let changes_ref = changes.clone();
+
+        let source = element.first_element_child().unwrap();
+        let target = element.last_element_child().unwrap();
+
+        let mut list = SortableList::builder("data-id", String::clone)
+            .bind(&source, models[0].clone())
+            .bind(&target, models[1].clone())
+            .on_change(move |container, items| {
+                changes_ref
+                    .borrow_mut()
+                    .push((container.clone(), items.to_vec()));
+            })
+            .build(Options::default());
+
+        let item = source.first_element_child().unwrap();
+        drag_into(list.drake_mut(), &item, &target, None);
+
+        let expected = vec![
+            (target, vec![String::from("1_0"), String::from("0_0")]),
+            (source, vec![]),
+        ];
+        assert_eq!(*changes.borrow(), expected);
+    };
+
+    run_dom_test(test, &html);
+}
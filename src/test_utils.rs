@@ -44,3 +44,74 @@ pub fn generate_draggable_containers(
         )
     })
 }
+
+/// Collects the children of `element`, i.e. the generated containers
+#[cfg(feature = "web-sys")]
+pub fn containers(element: &Element) -> Vec<Element> {
+    let containers = element.children();
+    (0..containers.length())
+        .map(|i| containers.item(i))
+        .map(Option::unwrap)
+        .collect()
+}
+
+/// Like [`generate_draggable_containers`], but tags each item with a
+/// `data-id` of `"{container}_{item}"` instead of an `id`
+#[cfg(feature = "web-sys")]
+pub fn keyed_containers(containers: u32, items: u32) -> String {
+    (0..containers).fold(String::new(), |acc, i| {
+        let items = (0..items).fold(String::new(), |acc, j| {
+            format!("{}<div data-id=\"{}_{}\"></div>", acc, i, j)
+        });
+        format!("{}<div id=\"cnt_{}\">{}</div>", acc, i, items)
+    })
+}
+
+/// Dispatches a `keydown` of `key` on `target`, as if it were focused
+#[cfg(feature = "web-sys")]
+pub fn press(target: &Element, key: &str) {
+    let init = KeyboardEventInit::new();
+    init.set_key(key);
+    init.set_bubbles(true);
+    init.set_cancelable(true);
+    let event =
+        KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init)
+            .unwrap();
+    target.dispatch_event(&event).unwrap();
+}
+
+/// The `id`s of the children of `container`, in order
+#[cfg(feature = "web-sys")]
+pub fn ids(container: &Element) -> Vec<String> {
+    let children = container.children();
+    (0..children.length())
+        .map(|i| children.item(i).unwrap().id())
+        .collect()
+}
+
+/// Collects the generated containers, like [`containers`], after copying the
+/// `id` of every container and item to its `data-id`
+#[cfg(feature = "web-sys")]
+pub fn with_ids(element: &Element) -> Vec<Element> {
+    let containers = containers(element);
+    for container in &containers {
+        container.set_attribute("data-id", &container.id()).unwrap();
+        let items = container.children();
+        for i in 0..items.length() {
+            let item = items.item(i).unwrap();
+            item.set_attribute("data-id", &item.id()).unwrap();
+        }
+    }
+    containers
+}
+
+/// Waits for `count` animation frames to be rendered
+pub async fn frames(count: u32) {
+    let window = web_sys::window().unwrap();
+    for _ in 0..count {
+        let frame = js_sys::Promise::new(&mut |resolve, _reject| {
+            window.request_animation_frame(&resolve).unwrap();
+        });
+        wasm_bindgen_futures::JsFuture::from(frame).await.unwrap();
+    }
+}
@@ -0,0 +1,123 @@
+//! Adapters for working with [`Element`](web_sys::Element) instead of
+//! `JsValue`
+//!
+//! Requires that feature `web-sys` be turned on.
+//!
+//! Each adapter wraps a closure taking typed arguments into the closure type
+//! expected by the matching field of [`Options`](crate::Options). Arguments
+//! that Dragula may pass as `null`, such as `sibling`, are given as an
+//! `Option`.
+//!
+//! ```no_run
+//! use dragula::*;
+//! use dragula::typed;
+//! # use wasm_bindgen::JsValue;
+//!
+//! # let element = JsValue::TRUE;
+//! //--snip--
+//!
+//! let options = Options {
+//!     is_container: typed::is_container(|el| {
+//!         el.class_list().contains("drag-container")
+//!     }),
+//!     accepts: typed::accepts(|_el, target, _source, sibling| {
+//!         // Only allow appending to the end of the "done" column
+//!         target.id() != "done" || sibling.is_none()
+//!     }),
+//!     copy: typed::copy(|_el, source| source.id() == "palette"),
+//!     ..Options::default()
+//! };
+//!
+//! let drake = dragula_options(&[element], options);
+//!
+//! //--snip--
+//! ```
+use crate::event::sibling;
+use crate::options::CopyValue;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+
+fn element(val: &JsValue) -> &Element {
+    val.unchecked_ref()
+}
+
+fn nullable_element(val: JsValue) -> Option<Element> {
+    sibling(val).map(JsCast::unchecked_into)
+}
+
+/// Wraps a closure for [`Options::is_container`](crate::Options::is_container)
+///
+/// The closure signature is `(el)`.
+pub fn is_container<F>(mut f: F) -> Box<dyn FnMut(JsValue) -> bool>
+where
+    F: FnMut(&Element) -> bool + 'static,
+{
+    Box::new(move |el| f(element(&el)))
+}
+
+/// Wraps a closure for [`Options::moves`](crate::Options::moves)
+///
+/// The closure signature is `(el, source, handle, sibling)`.
+pub fn moves<F>(
+    mut f: F,
+) -> Box<dyn FnMut(JsValue, JsValue, JsValue, JsValue) -> bool>
+where
+    F: FnMut(&Element, &Element, &Element, Option<&Element>) -> bool + 'static,
+{
+    Box::new(move |el, source, handle, sib| {
+        let sib = nullable_element(sib);
+        f(
+            element(&el),
+            element(&source),
+            element(&handle),
+            sib.as_ref(),
+        )
+    })
+}
+
+/// Wraps a closure for [`Options::accepts`](crate::Options::accepts)
+///
+/// The closure signature is `(el, target, source, sibling)`.
+pub fn accepts<F>(
+    mut f: F,
+) -> Box<dyn FnMut(JsValue, JsValue, JsValue, JsValue) -> bool>
+where
+    F: FnMut(&Element, &Element, &Element, Option<&Element>) -> bool + 'static,
+{
+    Box::new(move |el, target, source, sib| {
+        let sib = nullable_element(sib);
+        f(
+            element(&el),
+            element(&target),
+            element(&source),
+            sib.as_ref(),
+        )
+    })
+}
+
+/// Wraps a closure for [`Options::invalid`](crate::Options::invalid)
+///
+/// The closure signature is `(el, handle)`.
+pub fn invalid<F>(mut f: F) -> Box<dyn FnMut(JsValue, JsValue) -> bool>
+where
+    F: FnMut(&Element, &Element) -> bool + 'static,
+{
+    Box::new(move |el, handle| f(element(&el), element(&handle)))
+}
+
+/// Wraps a closure as a [`CopyValue::Func`](crate::options::CopyValue::Func)
+/// for [`Options::copy`](crate::Options::copy)
+///
+/// The closure signature is `(el, source)`.
+pub fn copy<F>(mut f: F) -> CopyValue
+where
+    F: FnMut(&Element, &Element) -> bool + 'static,
+{
+    CopyValue::Func(Box::new(move |el, source| {
+        f(element(&el), element(&source))
+    }))
+}
+
+#[cfg(test)]
+mod test;
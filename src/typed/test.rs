@@ -0,0 +1,99 @@
+use super::*;
+use crate::test_utils::*;
+use crate::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn typed_is_container_includes_correctly() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 3);
+
+    let test = |element: &Element| {
+        let in_containers: Vec<JsValue> = vec![];
+        let options = Options {
+            is_container: is_container(|el| el.id() == "cnt_0"),
+            ..Options::default()
+        };
+        let drake = dragula_options(&in_containers, options);
+
+        let item = element.first_element_child().unwrap();
+        let item = item.first_element_child().unwrap();
+
+        assert!(drake.can_move(&item));
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn typed_moves_receives_elements() {
+    console_error_panic_hook::set_once();
+
+    let html = generate_draggable_containers(2, 3);
+
+    let test = |element: &Element| {
+        let containers = element.children();
+        let in_containers: Vec<_> = (0..containers.length())
+            .map(|i| containers.item(i))
+            .map(Option::unwrap)
+            .collect();
+
+        let args = Rc::new(RefCell::new(None));
+        let args_ref = args.clone();
+        let options = Options {
+            moves: moves(move |el, source, handle, sibling| {
+                *args_ref.borrow_mut() = Some((
+                    el.clone(),
+                    source.clone(),
+                    handle.clone(),
+                    sibling.cloned(),
+                ));
+                true
+            }),
+            ..Options::default()
+        };
+        let drake = dragula_options(&in_containers, options);
+
+        let source = in_containers.first().unwrap();
+        let item = source.first_element_child().unwrap();
+
+        assert!(drake.can_move(&item));
+
+        let expected = (
+            item.clone(),
+            source.clone(),
+            item.clone(),
+            item.next_element_sibling(),
+        );
+        assert_eq!(args.borrow_mut().take(), Some(expected));
+    };
+
+    run_dom_test(test, &html);
+}
+
+#[wasm_bindgen_test]
+fn typed_accepts_maps_null_sibling_to_none() {
+    console_error_panic_hook::set_once();
+
+    let mut accepts =
+        accepts(|_el, _target, _source, sibling| sibling.is_none());
+
+    assert!(accepts(
+        JsValue::UNDEFINED,
+        JsValue::UNDEFINED,
+        JsValue::UNDEFINED,
+        JsValue::NULL,
+    ));
+}
+
+#[wasm_bindgen_test]
+fn typed_copy_is_func() {
+    let copy = copy(|_el, _source| true);
+
+    assert!(matches!(copy, CopyValue::Func(_)));
+}